@@ -60,7 +60,7 @@ impl SemverVersion {
             + "."
             + Cow::Owned(self.minor.to_string())
             + "."
-            + Cow::Owned(self.minor.to_string())
+            + Cow::Owned(self.patch.to_string())
     }
 }
 
@@ -1,26 +1,34 @@
-use std::{borrow::Borrow, sync::Arc};
+use std::{borrow::Borrow, sync::Arc, time::Duration};
 
-use async_channel::{bounded, Receiver};
 use async_lock::RwLock;
 use ed25519_dalek::Signature;
-use wallet_adapter_common::{clusters::Cluster, signin_standard::SignInOutput};
+use wallet_adapter_common::{
+    clusters::{Cluster, CustomCluster},
+    signin_standard::SignInOutput,
+    KnownWallet, WalletCatalog,
+};
 use web_sys::{js_sys::Object, Document, Window};
 
 use crate::{
-    events::InitEvents, send_wallet_event, SendOptions, SignedMessageOutput, SigninInput, Wallet,
-    WalletAccount, WalletError, WalletEvent, WalletEventReceiver, WalletEventSender, WalletResult,
-    WalletStorage,
+    events::InitEvents, send_wallet_event_error, AdapterCancelHandle, AutoConnect,
+    EventsSubscription, FeatureParser, SendOptions, SignAndSendTransactionMode,
+    SignedMessageOutput, SigninInput, Wallet, WalletAccount, WalletError, WalletEvent,
+    WalletEventBus, WalletEventReceiver, WalletEventSender, WalletEventSubscription,
+    WalletListOptions, WalletListing, WalletRequests, WalletResult, WalletStorage,
 };
 
 /// Contains the connected wallet and account.
 /// Containing them in the same struct allows passing of this type
 /// by containing it in types like [Arc] and [RwLock] when moving the type
 /// out of it's scope like in background tasks or async functions (`async move`).
-#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord)]
+/// The `change` listener is left out when comparing [ConnectionInfo]s
+#[derive(Debug, Clone, Default)]
 pub struct ConnectionInfo {
     wallet: Option<Wallet>,
     account: Option<WalletAccount>,
+    accounts: Vec<WalletAccount>,
     previous_accounts: Vec<WalletAccount>,
+    subscription: Option<EventsSubscription>,
 }
 
 impl ConnectionInfo {
@@ -43,26 +51,97 @@ impl ConnectionInfo {
         self
     }
 
+    /// Set the accounts the connected wallet authorized the app to use
+    pub fn set_accounts(&mut self, accounts: Vec<WalletAccount>) -> &mut Self {
+        self.accounts = accounts;
+
+        self
+    }
+
+    /// Set the `standard:events` subscription of the connected wallet
+    pub fn set_subscription(&mut self, subscription: EventsSubscription) -> &mut Self {
+        self.subscription.replace(subscription);
+
+        self
+    }
+
+    /// Remove the `change` listener of the connected wallet if it was subscribed to
+    pub fn unsubscribe(&mut self) -> WalletResult<()> {
+        if let Some(subscription) = self.subscription.take() {
+            subscription.unsubscribe()?;
+        }
+
+        Ok(())
+    }
+
     /// Send a connect request to the browser wallet
     pub async fn connect(&mut self, sender: WalletEventSender) -> WalletResult<WalletAccount> {
-        let wallet = self.connected_wallet()?;
+        let wallet = self.connected_wallet()?.clone();
+
+        let accounts = wallet.features.connect.call_connect(false).await?;
+
+        self.set_connected(wallet, accounts, sender).await
+    }
 
-        let connected_account = wallet.features.connect.call_connect().await?;
+    /// Set the `wallet` as connected with the `accounts` it authorized.
+    /// The first account becomes the connected account and [WalletEvent::Connected] is emitted
+    pub(crate) async fn set_connected(
+        &mut self,
+        wallet: Wallet,
+        accounts: Vec<WalletAccount>,
+        sender: WalletEventSender,
+    ) -> WalletResult<WalletAccount> {
+        let connected_account = accounts
+            .first()
+            .cloned()
+            .ok_or(WalletError::ConnectHasNoAccounts)?;
 
-        self.set_account(connected_account.clone());
+        self.set_wallet(wallet)
+            .set_accounts(accounts)
+            .set_account(connected_account.clone());
 
-        send_wallet_event(WalletEvent::Connected(connected_account.clone()), sender).await;
+        sender.publish(WalletEvent::Connected(connected_account.clone()));
 
         Ok(connected_account)
     }
 
-    /// Set the disconnected account
+    /// Switch the connected account to another authorized account
+    /// without sending a new connect request to the browser wallet.
+    /// [WalletEvent::AccountChanged] is emitted if the connected account changes
+    pub async fn switch_account(
+        &mut self,
+        address: &str,
+        sender: WalletEventSender,
+    ) -> WalletResult<WalletAccount> {
+        self.connected_wallet()?;
+
+        let account = self
+            .accounts
+            .iter()
+            .find(|account| account.address() == address)
+            .cloned()
+            .ok_or(WalletError::AccountNotAuthorized(address.to_string()))?;
+
+        if self.account.as_ref() != Some(&account) {
+            self.push_previous_account();
+            self.set_account(account.clone());
+
+            sender.publish(WalletEvent::AccountChanged(account.clone()));
+        }
+
+        Ok(account)
+    }
+
+    /// Set the disconnected account and remove the `change` listener of the wallet
     pub async fn set_disconnected(&mut self, sender: WalletEventSender) -> &mut Self {
+        let _ = send_wallet_event_error()(self.unsubscribe(), sender.clone()).await;
+
         self.wallet.take();
         self.account.take();
+        self.accounts.clear();
         self.previous_accounts.clear();
 
-        send_wallet_event(WalletEvent::Disconnected, sender).await;
+        sender.publish(WalletEvent::Disconnected);
 
         self
     }
@@ -89,68 +168,67 @@ impl ConnectionInfo {
         self.account.as_ref()
     }
 
-    /// Emit an [event](WalletEvent) after processing the `[standard:events].on` result
+    /// Get all the [accounts](WalletAccount) the connected wallet
+    /// authorized the app to use, including the connected account
+    pub fn accounts(&self) -> &[WalletAccount] {
+        self.accounts.as_slice()
+    }
+
+    /// Emit an [event](WalletEvent) after processing the `[standard:events].on` result.
+    /// The `accounts` are all the accounts reported by the wallet.
     pub async fn emit_wallet_event(
         &mut self,
         wallet_name: &str,
-        account_processing: Option<WalletAccount>,
+        accounts: Vec<WalletAccount>,
         sender: WalletEventSender,
     ) {
         match self.connected_wallet() {
             Ok(wallet) => {
-                let event_outcome = match account_processing {
-                    Some(connected_account) => {
-                        if self.account.is_none()
-                            && self.wallet.is_none()
-                            && self.previous_accounts.is_empty()
-                        {
-                            self.set_account(connected_account.clone());
-
-                            WalletEvent::Connected(connected_account)
-                        } else if self.account.is_none()
-                            && self.wallet.is_some()
-                            && self.previous_accounts.iter().any(|wallet_account| {
-                                wallet_account.account.public_key
-                                    == connected_account.account.public_key
-                            })
-                        {
-                            self.push_previous_account();
-                            self.set_account(connected_account.clone());
-
-                            WalletEvent::Connected(connected_account)
-                        } else if wallet.name().as_bytes() == wallet_name.as_bytes()
-                            && self.account.is_none()
-                            && self.previous_accounts.iter().any(|wallet_account| {
-                                wallet_account.account.public_key
-                                    == connected_account.account.public_key
-                            })
-                        {
-                            self.push_previous_account();
-                            self.set_account(connected_account.clone());
-
-                            WalletEvent::Reconnected(connected_account)
-                        } else if wallet.name().as_bytes() == wallet_name.as_bytes()
-                            && self.account.is_some()
-                        {
-                            self.push_previous_account();
-                            self.set_account(connected_account.clone());
-
-                            WalletEvent::AccountChanged(connected_account)
-                        } else {
-                            WalletEvent::Skip
-                        }
+                let event_outcome = if wallet.name().as_bytes() != wallet_name.as_bytes() {
+                    WalletEvent::Skip
+                } else if accounts.is_empty() {
+                    // The wallet revoked every account so the listener is removed too
+                    self.set_disconnected(sender).await;
+
+                    return;
+                } else if let Some(connected_account) = self.account.clone() {
+                    // Keep the connected account if the wallet still authorizes it
+                    let next_account = accounts
+                        .iter()
+                        .find(|account| account.public_key() == connected_account.public_key())
+                        .unwrap_or(&accounts[0])
+                        .clone();
+
+                    if next_account == connected_account && accounts == self.accounts {
+                        WalletEvent::Skip
+                    } else {
+                        self.set_accounts(accounts);
+                        self.push_previous_account();
+                        self.set_account(next_account.clone());
+
+                        WalletEvent::AccountChanged(next_account)
                     }
-                    None => {
-                        if wallet.name().as_bytes() == wallet_name.as_bytes() {
-                            self.push_previous_account();
-                            WalletEvent::Disconnected
-                        } else {
-                            WalletEvent::Skip
-                        }
+                } else {
+                    let (connected_account, reconnected) = match accounts.iter().find(|account| {
+                        self.previous_accounts.iter().any(|wallet_account| {
+                            wallet_account.public_key() == account.public_key()
+                        })
+                    }) {
+                        Some(previous_account) => (previous_account.clone(), true),
+                        None => (accounts[0].clone(), false),
+                    };
+
+                    self.set_accounts(accounts)
+                        .set_account(connected_account.clone());
+
+                    if reconnected {
+                        WalletEvent::Reconnected(connected_account)
+                    } else {
+                        WalletEvent::Connected(connected_account)
                     }
                 };
 
-                send_wallet_event(event_outcome, sender).await
+                sender.publish(event_outcome)
             }
             Err(error) => {
                 web_sys::console::log_2(
@@ -172,6 +250,44 @@ impl ConnectionInfo {
     }
 }
 
+impl ConnectionInfo {
+    fn comparable(
+        &self,
+    ) -> (
+        &Option<Wallet>,
+        &Option<WalletAccount>,
+        &Vec<WalletAccount>,
+        &Vec<WalletAccount>,
+    ) {
+        (
+            &self.wallet,
+            &self.account,
+            &self.accounts,
+            &self.previous_accounts,
+        )
+    }
+}
+
+impl PartialEq for ConnectionInfo {
+    fn eq(&self, other: &Self) -> bool {
+        self.comparable() == other.comparable()
+    }
+}
+
+impl Eq for ConnectionInfo {}
+
+impl PartialOrd for ConnectionInfo {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ConnectionInfo {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.comparable().cmp(&other.comparable())
+    }
+}
+
 /// The [ConnectionInfo] wrapped in an `Arc<RwLock<T>>`
 pub type ConnectionInfoInner = Arc<RwLock<ConnectionInfo>>;
 
@@ -186,14 +302,17 @@ pub struct WalletAdapter {
     connection_info: ConnectionInfoInner,
     wallet_events: WalletEventReceiver,
     wallet_events_sender: WalletEventSender,
-    signal_receiver: Receiver<()>,
+    auto_connect: AutoConnect,
+    custom_clusters: Vec<CustomCluster>,
+    catalog: WalletCatalog,
+    requests: WalletRequests,
 }
 
 impl WalletAdapter {
     /// Get the `Window` and `Document` object in the current browser window,
     /// initialize the `AppReady` and `Register` events of the wallet standard
-    /// and creates a bounded channel with capacity default of 5 messages before capacity is filled.
-    /// Use [WalletAdapter::init_with_channel_capacity] to initialize with a desired channel capacity.
+    /// and create the [WalletEventBus] where each subscriber buffers up to 5 events.
+    /// Use [WalletAdapter::init_with_channel_capacity] to initialize with a desired capacity.
     pub fn init() -> WalletResult<Self> {
         let window = if let Some(window) = web_sys::window() {
             window
@@ -212,7 +331,9 @@ impl WalletAdapter {
 
     /// Get the `Window` and `Document` object in the current browser window,
     /// initialize the `AppReady` and `Register` events of the wallet standard
-    /// and creates a bounded channel with user-specified capacity.
+    /// and create the [WalletEventBus] where each subscriber buffers up to `capacity` events.
+    /// A subscriber that falls further behind drops its oldest events, as does the
+    /// deprecated shared receiver of [WalletAdapter::events] once it is full.
     pub fn init_with_channel_capacity(capacity: usize) -> WalletResult<Self> {
         let window = if let Some(window) = web_sys::window() {
             window
@@ -229,8 +350,8 @@ impl WalletAdapter {
         Self::init_with_channel_capacity_window_and_document(capacity, window, document)
     }
 
-    /// Same as [WalletAdapter::init] but a `capacity` value
-    /// can be passed to create an channel with a desired capacity
+    /// Same as [WalletAdapter::init_custom] but each subscriber of the
+    /// [WalletEventBus] buffers up to `capacity` events
     #[allow(clippy::arc_with_non_send_sync)]
     pub fn init_with_channel_capacity_window_and_document(
         capacity: usize,
@@ -239,17 +360,30 @@ impl WalletAdapter {
     ) -> WalletResult<Self> {
         let storage = WalletStorage::default();
 
-        let (sender, receiver) = bounded::<WalletEvent>(capacity);
-        let (_, signal_receiver) = bounded::<()>(capacity);
+        let sender = WalletEventBus::new(capacity);
+        let receiver = sender.receiver();
+        let connection_info = Arc::new(RwLock::new(ConnectionInfo::default()));
+        let requests = WalletRequests::new(window.clone());
+
+        let auto_connect = AutoConnect::new(
+            window.clone(),
+            connection_info.clone(),
+            storage.clone(),
+            sender.clone(),
+            requests.clone(),
+        );
 
         let mut new_self = Self {
             window: window.clone(),
             document,
             storage,
-            connection_info: Arc::new(RwLock::new(ConnectionInfo::default())),
+            connection_info,
             wallet_events: receiver,
             wallet_events_sender: sender,
-            signal_receiver,
+            auto_connect,
+            custom_clusters: Vec::default(),
+            catalog: WalletCatalog::default(),
+            requests,
         };
 
         InitEvents::new(&window).init(&mut new_self)?;
@@ -264,43 +398,94 @@ impl WalletAdapter {
         Self::init_with_channel_capacity_window_and_document(5, window, document)
     }
 
+    /// The [WalletEventBus] that publishes the events of the adapter
+    pub(crate) fn events_sender(&self) -> WalletEventSender {
+        self.wallet_events_sender.clone()
+    }
+
+    pub(crate) fn connection_info_inner(&self) -> ConnectionInfoInner {
+        self.connection_info.clone()
+    }
+
     /// Listen for [WalletEvent] to be notified when a wallet
     /// receives `connected`, `disconnected` and `accountChanged` events triggered
     /// when the `change` event is dispatched by a connected browser extension
+    /// and when a wallet registers or unregisters.
+    /// All the clones share one receiver so each event is received by only one of them
+    /// and once the channel is full the oldest event is dropped without notice
+    #[deprecated(
+        note = "use `WalletAdapter::subscribe` which delivers every event to every subscriber and reports dropped events as `WalletError::EventsLagged`"
+    )]
     pub fn events(&self) -> WalletEventReceiver {
         self.wallet_events.clone()
     }
 
-    /// Send a connect request to the browser wallet
+    /// Subscribe to every [WalletEvent] emitted after subscribing.
+    /// Each subscription buffers up to the capacity the adapter was initialized with, see [WalletEventBus]
+    pub fn subscribe(&self) -> WalletEventSubscription {
+        self.wallet_events_sender.subscribe()
+    }
+
+    /// Same as [Self::subscribe] but the first event received is the current connection state,
+    /// see [WalletEventBus::subscribe_with_replay]
+    pub fn subscribe_with_replay(&self) -> WalletEventSubscription {
+        self.wallet_events_sender.subscribe_with_replay()
+    }
+
+    /// Send a connect request to the browser wallet.
+    /// The `change` listener of a previously connected wallet is removed and,
+    /// if the wallet supports `standard:events`, a new listener is added.
+    /// Failing to add the listener does not fail the connection, the error is sent
+    /// as a [WalletEvent::BackgroundTaskError]
     pub async fn connect(&mut self, wallet: Wallet) -> WalletResult<WalletAccount> {
         let wallet_name = wallet.name().to_string();
         let sender = self.wallet_events_sender.clone();
-        let signal_receiver = self.signal_receiver.clone();
+        let standard_events = wallet.standard_events();
 
-        if self.connection_info().await.connected_wallet().is_ok() {
-            let capacity = signal_receiver.capacity().unwrap_or(5);
-            let (_, signal_receiver) = bounded::<()>(capacity);
-            self.signal_receiver = signal_receiver;
-        }
-
-        let wallet_account = self
-            .connection_info
-            .write()
-            .await
-            .set_wallet(wallet)
-            .connect(sender.clone())
+        // The previously connected wallet is kept if the connect request
+        // is rejected, times out or is cancelled
+        let accounts = self
+            .requests
+            .run(wallet.features.connect.call_connect(false))
             .await?;
 
-        self.connection_info()
-            .await
-            .connected_wallet()?
-            .call_on_event(
-                self.connection_info.clone(),
-                wallet_name,
-                sender,
-                signal_receiver,
-            )
-            .await?;
+        let wallet_account = {
+            let mut connection_info = self.connection_info.write().await;
+
+            let unsubscribed = connection_info.unsubscribe();
+            let _ = send_wallet_event_error()(unsubscribed, sender.clone()).await;
+
+            connection_info
+                .set_connected(wallet.clone(), accounts, sender.clone())
+                .await?
+        };
+
+        // The wallet is connected even if listening for `change` events fails
+        // so the error is sent as a [WalletEvent::BackgroundTaskError]
+        if standard_events {
+            let outcome = wallet
+                .call_on_event(
+                    self.connection_info.clone(),
+                    self.storage.clone(),
+                    wallet_name.clone(),
+                    sender.clone(),
+                )
+                .await;
+
+            if let Ok(subscription) = send_wallet_event_error()(outcome, sender.clone()).await {
+                self.connection_info
+                    .write()
+                    .await
+                    .set_subscription(subscription);
+            }
+        }
+
+        let _ = send_wallet_event_error()(
+            self.auto_connect
+                .remember(&wallet_name, wallet_account.address()),
+            sender,
+        )
+        .await;
 
         Ok(wallet_account)
     }
@@ -313,16 +498,127 @@ impl WalletAdapter {
         self.connect(wallet).await
     }
 
-    /// Send a disconnect request to the browser wallet
-    pub async fn disconnect(&mut self) {
+    /// Get all the [accounts](WalletAccount) the connected wallet authorized the app to use
+    pub async fn accounts(&self) -> Vec<WalletAccount> {
+        self.connection_info().await.accounts().to_vec()
+    }
+
+    /// Switch the connected account to another account authorized by the connected wallet
+    /// without reconnecting. [WalletEvent::AccountChanged] is emitted if the connected account changes
+    pub async fn switch_account(&self, address: &str) -> WalletResult<WalletAccount> {
+        let sender = self.wallet_events_sender.clone();
+
+        let mut connection_info = self.connection_info.write().await;
+        let wallet_account = connection_info
+            .switch_account(address, sender.clone())
+            .await?;
+        let wallet_name = connection_info.connected_wallet()?.name().to_string();
+        drop(connection_info);
+
+        let _ = send_wallet_event_error()(
+            self.auto_connect
+                .remember(&wallet_name, wallet_account.address()),
+            sender,
+        )
+        .await;
+
+        Ok(wallet_account)
+    }
+
+    /// Send a disconnect request to the browser wallet if it supports `standard:disconnect`,
+    /// then remove the `change` listener of the wallet and emit [WalletEvent::Disconnected].
+    /// The adapter stays connected if the wallet fails to disconnect,
+    /// use [Self::force_disconnect] to disconnect anyway
+    pub async fn disconnect(&mut self) -> WalletResult<()> {
+        self.disconnect_wallet().await?;
+        self.clear_connection().await;
+
+        Ok(())
+    }
+
+    /// Same as [Self::disconnect] but the adapter is disconnected even if the wallet
+    /// fails to disconnect. The error from the wallet is still returned
+    pub async fn force_disconnect(&mut self) -> WalletResult<()> {
+        let outcome = self.disconnect_wallet().await;
+        self.clear_connection().await;
+
+        outcome
+    }
+
+    async fn disconnect_wallet(&self) -> WalletResult<()> {
+        let wallet = self.connection_info().await.connected_wallet_raw().cloned();
+
+        match wallet {
+            Some(wallet) if wallet.standard_disconnect() => {
+                self.requests.run(wallet.disconnect()).await
+            }
+            _ => Ok(()),
+        }
+    }
+
+    async fn clear_connection(&mut self) {
         let sender = self.wallet_events_sender.clone();
 
         self.connection_info
             .write()
             .await
-            .set_disconnected(sender)
+            .set_disconnected(sender.clone())
             .await;
-        self.signal_receiver.close();
+
+        if self.auto_connect.enabled() {
+            let _ = send_wallet_event_error()(self.auto_connect.forget(), sender).await;
+        }
+    }
+
+    /// Opt in to auto-connect. The name of the connected wallet and the address of the
+    /// connected account are persisted in the `localStorage` of the browser and once that wallet
+    /// registers on a later page load a silent `standard:connect` request is sent.
+    /// The adapter emits [WalletEvent::Reconnected] if the wallet approves the request
+    /// and stays disconnected otherwise without the user being prompted
+    pub fn enable_auto_connect(&self) {
+        self.auto_connect.set_enabled(true);
+
+        // The wallet might have registered before auto-connect was enabled
+        if let Ok(Some((wallet_name, _))) = self.auto_connect.remembered() {
+            if let Some(wallet) = self.storage.get_wallet(&wallet_name) {
+                self.auto_connect.on_register(&wallet);
+            }
+        }
+    }
+
+    /// Opt out of auto-connect and remove the persisted wallet, account
+    /// and most recently used wallet
+    pub fn disable_auto_connect(&self) -> WalletResult<()> {
+        self.auto_connect.set_enabled(false);
+
+        self.auto_connect.forget()
+    }
+
+    /// Set how long a request to the wallet, like connecting or signing, is waited for
+    /// before it returns [WalletError::Timeout]. `None`, the default, waits until
+    /// the wallet settles the request which some wallets never do if the user closes their popup.
+    /// The silent connect request of [AutoConnect] uses the same timeout
+    pub fn set_request_timeout(&mut self, timeout: Option<Duration>) -> &mut Self {
+        self.requests.set_timeout(timeout);
+
+        self
+    }
+
+    /// Get the timeout of requests to the wallet
+    pub fn request_timeout(&self) -> Option<Duration> {
+        self.requests.timeout()
+    }
+
+    /// Get the [AdapterCancelHandle] shared by every request to the wallet made by this adapter
+    /// until it is cancelled. Cancelling it makes all the pending requests, including
+    /// the silent reconnect of [AutoConnect], return [WalletError::RequestCancelled]
+    pub fn adapter_cancel_handle(&self) -> AdapterCancelHandle {
+        self.requests.cancel_handle()
+    }
+
+    /// Get the [AutoConnect] state of the adapter
+    pub fn auto_connect(&self) -> &AutoConnect {
+        &self.auto_connect
     }
 
     /// Send a sign in request to the browser wallet to Sign In With Solana
@@ -331,10 +627,14 @@ impl WalletAdapter {
         signin_input: &SigninInput,
         public_key: [u8; 32],
     ) -> WalletResult<SignInOutput> {
-        self.connection_info()
-            .await
-            .connected_wallet()?
-            .sign_in(signin_input, public_key)
+        self.requests
+            .run(async {
+                self.connection_info()
+                    .await
+                    .connected_wallet()?
+                    .sign_in(signin_input, public_key)
+                    .await
+            })
             .await
     }
 
@@ -345,37 +645,77 @@ impl WalletAdapter {
         cluster: Cluster,
         options: SendOptions,
     ) -> WalletResult<Signature> {
-        let connection_info = self.connection_info();
-
-        connection_info
-            .await
+        let connection_info = self.connection_info().await;
+        let request = connection_info
             .connected_wallet()?
             .sign_and_send_transaction(
                 transaction_bytes,
                 cluster,
                 options,
-                self.connection_info().await.connected_account()?,
-            )
-            .await
+                connection_info.connected_account()?,
+            );
+
+        self.requests.run(request).await
     }
 
-    /// Send a connect request to the browser wallet
+    /// Send the `transactions` to the browser wallet in a single sign and send request,
+    /// each with its own [SendOptions]. The `mode` determines whether the wallet sends the transactions
+    /// in parallel or one after the other. A result is returned for each transaction
+    /// in the same order since some transactions might be sent while others fail.
+    pub async fn sign_and_send_all_transactions(
+        &self,
+        transactions: &[(&[u8], SendOptions)],
+        cluster: Cluster,
+        mode: SignAndSendTransactionMode,
+    ) -> WalletResult<Vec<WalletResult<Signature>>> {
+        let connection_info = self.connection_info().await;
+        let request = connection_info
+            .connected_wallet()?
+            .sign_and_send_all_transactions(
+                transactions,
+                cluster,
+                mode,
+                connection_info.connected_account()?,
+            );
+
+        self.requests.run(request).await
+    }
+
+    /// Send a sign transaction request to the browser wallet.
+    /// The signed transaction is only returned if its message is the one sent to be signed
+    /// and the connected account's signature is valid for that message
     pub async fn sign_transaction(
         &self,
         transaction_bytes: &[u8],
         cluster: Option<Cluster>,
     ) -> WalletResult<Vec<Vec<u8>>> {
-        let connection_info = self.connection_info();
+        let connection_info = self.connection_info().await;
+        let request = connection_info.connected_wallet()?.sign_transaction(
+            transaction_bytes,
+            cluster,
+            connection_info.connected_account()?,
+        );
+
+        self.requests.run(request).await
+    }
 
-        connection_info
-            .await
-            .connected_wallet()?
-            .sign_transaction(
-                transaction_bytes,
-                cluster,
-                self.connection_info().await.connected_account()?,
-            )
-            .await
+    /// Send the `transactions` to the browser wallet in a single sign transaction request
+    /// so that the user approves all of them in one prompt.
+    /// The signed transactions are returned in the same order as the `transactions`
+    /// and are verified in the same way as [Self::sign_transaction]
+    pub async fn sign_all_transactions(
+        &self,
+        transactions: &[&[u8]],
+        cluster: Option<Cluster>,
+    ) -> WalletResult<Vec<Vec<u8>>> {
+        let connection_info = self.connection_info().await;
+        let request = connection_info.connected_wallet()?.sign_all_transactions(
+            transactions,
+            cluster,
+            connection_info.connected_account()?,
+        );
+
+        self.requests.run(request).await
     }
 
     /// Send a sign message request to the browser wallet
@@ -383,13 +723,12 @@ impl WalletAdapter {
         &self,
         message: &'a [u8],
     ) -> WalletResult<SignedMessageOutput<'a>> {
-        let connection_info = self.connection_info();
-
-        self.connection_info()
-            .await
+        let connection_info = self.connection_info().await;
+        let request = connection_info
             .connected_wallet()?
-            .sign_message(message, connection_info.await.connected_account()?)
-            .await
+            .sign_message(message, connection_info.connected_account()?);
+
+        self.requests.run(request).await
     }
 
     /// Check if an [account](WalletAccount) is connected
@@ -429,6 +768,7 @@ impl WalletAdapter {
     }
 
     /// Get the clusters supported by the connected wallet
+    /// followed by the [custom clusters](WalletAdapter::add_cluster) on the same chains
     pub async fn clusters(&self) -> WalletResult<Vec<Cluster>> {
         let mut clusters = Vec::<Cluster>::default();
 
@@ -445,14 +785,134 @@ impl WalletAdapter {
             clusters.push(Cluster::TestNet);
         }
 
+        let custom_clusters = self
+            .custom_clusters
+            .iter()
+            .filter(|custom| clusters.contains(&custom.genesis()))
+            .cloned()
+            .map(Cluster::Custom)
+            .collect::<Vec<Cluster>>();
+        clusters.extend(custom_clusters);
+
         Ok(clusters)
     }
 
-    /// Get the registered wallets
+    /// Add a user defined cluster, replacing any custom cluster with the same name.
+    /// It is listed in [WalletAdapter::clusters] if the connected wallet supports its `solana:*` chain
+    pub fn add_cluster(&mut self, cluster: CustomCluster) -> &mut Self {
+        self.custom_clusters
+            .retain(|custom| custom.name() != cluster.name());
+        self.custom_clusters.push(cluster);
+
+        self
+    }
+
+    /// Get the user defined clusters
+    pub fn custom_clusters(&self) -> &[CustomCluster] {
+        self.custom_clusters.as_slice()
+    }
+
+    /// Get the registered wallets in the order they first registered
     pub fn wallets(&self) -> Vec<Wallet> {
         self.storage.borrow().get_wallets()
     }
 
+    /// Register a [FeatureParser] for a namespaced feature not parsed by this library.
+    /// The feature is parsed when a wallet registers, including the wallets that already
+    /// registered, and the typed handle is available from [Features::parsed](crate::Features::parsed)
+    pub async fn register_feature_parser<T: FeatureParser + 'static>(&mut self) -> &mut Self {
+        self.storage
+            .clone_inner()
+            .borrow_mut()
+            .register_feature_parser::<T>();
+
+        if let Some(wallet) = self.connection_info.write().await.wallet.as_mut() {
+            self.storage.parse_features(wallet);
+        }
+
+        self
+    }
+
+    /// Get the registered wallets filtered and ranked by the [WalletListOptions].
+    /// If the options do not set a recently used wallet, the wallet most recently
+    /// connected to is ranked first
+    pub fn ranked_wallets(&self, options: &WalletListOptions) -> Vec<Wallet> {
+        let mut options = options.clone();
+
+        if options.recently_used().is_none() {
+            if let Some(wallet_name) = self.recently_used_wallet() {
+                options.set_recently_used(&wallet_name);
+            }
+        }
+
+        options.apply(self.wallets(), |wallet| &wallet.data)
+    }
+
+    /// Add a wallet to the [WalletCatalog], replacing the entry with the same name
+    pub fn add_known_wallet(&mut self, known_wallet: KnownWallet) -> &mut Self {
+        self.catalog.add(known_wallet);
+
+        self
+    }
+
+    /// Remove a wallet from the [WalletCatalog]
+    pub fn remove_known_wallet(&mut self, wallet_name: &str) -> &mut Self {
+        self.catalog.remove(wallet_name);
+
+        self
+    }
+
+    /// Get the catalog of known wallets
+    pub fn catalog(&self) -> &WalletCatalog {
+        &self.catalog
+    }
+
+    /// Get one list for a wallet picker. The registered wallets are listed first as ranked by
+    /// [WalletAdapter::ranked_wallets], followed by the wallets in the [WalletCatalog]
+    /// that are not registered. Wallets that are not registered are not filtered by the
+    /// [WalletListOptions] since their features and chains are not known
+    pub fn wallet_listings(&self, options: &WalletListOptions) -> Vec<WalletListing> {
+        let mobile = self.is_mobile();
+
+        let registered = self.ranked_wallets(options);
+        let not_registered = self
+            .catalog
+            .wallets()
+            .iter()
+            .filter(|known_wallet| self.storage.get_wallet(known_wallet.name()).is_none())
+            .map(|known_wallet| {
+                WalletListing::new(Option::None, Some(known_wallet.clone()), mobile)
+            })
+            .collect::<Vec<WalletListing>>();
+
+        registered
+            .into_iter()
+            .map(|wallet| {
+                let known_wallet = self.catalog.get(wallet.name()).cloned();
+
+                WalletListing::new(Some(wallet), known_wallet, mobile)
+            })
+            .chain(not_registered)
+            .collect()
+    }
+
+    /// Check whether the user agent of the browser is a mobile device
+    pub fn is_mobile(&self) -> bool {
+        self.window
+            .navigator()
+            .user_agent()
+            .is_ok_and(|user_agent| {
+                ["Android", "iPhone", "iPad", "iPod", "Mobile"]
+                    .iter()
+                    .any(|device| user_agent.contains(device))
+            })
+    }
+
+    /// Get the name of the wallet most recently connected to
+    pub fn recently_used_wallet(&self) -> Option<String> {
+        self.auto_connect.recently_used()
+    }
+
     /// Get a certain wallet by its name
     pub fn get_wallet(&self, wallet_name: &str) -> WalletResult<Wallet> {
         self.storage
@@ -534,6 +994,15 @@ impl WalletAdapter {
             .solana_sign_and_send_transaction())
     }
 
+    /// Check if the connected wallet supports `solana:signAndSendAllTransactions` feature
+    pub async fn solana_sign_and_send_all_transactions(&self) -> WalletResult<bool> {
+        Ok(self
+            .connection_info()
+            .await
+            .connected_wallet()?
+            .solana_sign_and_send_all_transactions())
+    }
+
     /// Check if the connected wallet supports `solana:signTransaction` feature
     pub async fn solana_sign_transaction(&self) -> WalletResult<bool> {
         Ok(self
@@ -552,3 +1021,46 @@ impl PartialEq for WalletAdapter {
     }
 }
 impl Eq for WalletAdapter {}
+
+#[cfg(test)]
+#[cfg(feature = "mock")]
+#[cfg(target_arch = "wasm32")]
+mod adapter_tests {
+    use wasm_bindgen_test::*;
+
+    use crate::{
+        mock::test_utils::{mock_with_account, registered},
+        MockAccount, MockFeature, WalletError, WalletEvent,
+    };
+
+    #[wasm_bindgen_test]
+    async fn switch_authorized_account() {
+        let mock =
+            mock_with_account("Mock Switch Account").add_account(MockAccount::new([8u8; 32]));
+        let (mock, mut adapter) = registered(mock);
+        let events = adapter.subscribe();
+
+        adapter.connect_by_name(mock.name()).await.unwrap();
+        assert_eq!(adapter.accounts().await.len(), 2);
+        assert!(matches!(events.recv().await, Ok(WalletEvent::Connected(_))));
+
+        let second_address = mock.accounts()[1].address();
+        let switched = adapter.switch_account(&second_address).await.unwrap();
+
+        assert_eq!(switched.address(), second_address);
+        assert_eq!(
+            adapter.connection_info().await.connected_account().unwrap(),
+            &switched
+        );
+        assert_eq!(
+            events.recv().await,
+            Ok(WalletEvent::AccountChanged(switched))
+        );
+        assert_eq!(mock.calls_for(MockFeature::Connect).len(), 1);
+
+        assert_eq!(
+            adapter.switch_account("unknown").await,
+            Err(WalletError::AccountNotAuthorized("unknown".to_string()))
+        );
+    }
+}
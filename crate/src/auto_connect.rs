@@ -0,0 +1,300 @@
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+};
+
+use web_sys::{Storage, Window};
+
+use crate::{
+    send_wallet_event_error, ConnectionInfoInner, Wallet, WalletError, WalletEvent,
+    WalletEventSender, WalletRequests, WalletResult, WalletStorage,
+};
+
+/// The `localStorage` key of the name of the last connected wallet
+pub const AUTO_CONNECT_WALLET_KEY: &str = "wallet-adapter:auto-connect:wallet";
+
+/// The `localStorage` key of the address of the last connected account
+pub const AUTO_CONNECT_ACCOUNT_KEY: &str = "wallet-adapter:auto-connect:account";
+
+/// The `localStorage` key of the name of the most recently connected wallet.
+/// It is only persisted if auto-connect is enabled
+pub const RECENTLY_USED_WALLET_KEY: &str = "wallet-adapter:recently-used";
+
+/// Silently reconnects to the last connected wallet once that wallet registers.
+/// The name of the wallet and the address of the account are persisted in
+/// the `localStorage` of the browser. This is opt-in, see [crate::WalletAdapter::enable_auto_connect]
+#[derive(Debug, Clone)]
+pub struct AutoConnect {
+    window: Window,
+    enabled: Rc<Cell<bool>>,
+    attempted: Rc<Cell<bool>>,
+    recently_used: Rc<RefCell<Option<String>>>,
+    connection_info: ConnectionInfoInner,
+    storage: WalletStorage,
+    sender: WalletEventSender,
+    requests: WalletRequests,
+}
+
+impl AutoConnect {
+    pub(crate) fn new(
+        window: Window,
+        connection_info: ConnectionInfoInner,
+        storage: WalletStorage,
+        sender: WalletEventSender,
+        requests: WalletRequests,
+    ) -> Self {
+        Self {
+            window,
+            enabled: Rc::default(),
+            attempted: Rc::default(),
+            recently_used: Rc::default(),
+            connection_info,
+            storage,
+            sender,
+            requests,
+        }
+    }
+
+    /// Is auto-connect enabled
+    pub fn enabled(&self) -> bool {
+        self.enabled.get()
+    }
+
+    pub(crate) fn set_enabled(&self, enabled: bool) {
+        self.enabled.set(enabled);
+    }
+
+    /// The name of the last connected wallet and the address of the last connected account
+    pub fn remembered(&self) -> WalletResult<Option<(String, Option<String>)>> {
+        let storage = self.local_storage()?;
+
+        let wallet_name = match storage.get_item(AUTO_CONNECT_WALLET_KEY)? {
+            Some(wallet_name) => wallet_name,
+            None => return Ok(Option::None),
+        };
+
+        Ok(Some((
+            wallet_name,
+            storage.get_item(AUTO_CONNECT_ACCOUNT_KEY)?,
+        )))
+    }
+
+    /// The name of the most recently connected wallet. It is only read from
+    /// the `localStorage` of the browser if auto-connect is enabled
+    pub fn recently_used(&self) -> Option<String> {
+        if let Some(wallet_name) = self.recently_used.borrow().as_ref() {
+            return Some(wallet_name.clone());
+        }
+
+        if !self.enabled() {
+            return Option::None;
+        }
+
+        self.local_storage()
+            .and_then(|storage| Ok(storage.get_item(RECENTLY_USED_WALLET_KEY)?))
+            .ok()
+            .flatten()
+    }
+
+    /// Remember the connected wallet as the most recently used wallet.
+    /// The wallet and account are only persisted if auto-connect is enabled
+    pub(crate) fn remember(&self, wallet_name: &str, address: &str) -> WalletResult<()> {
+        self.recently_used.replace(Some(wallet_name.to_string()));
+
+        if !self.enabled() {
+            return Ok(());
+        }
+
+        let storage = self.local_storage()?;
+        storage.set_item(RECENTLY_USED_WALLET_KEY, wallet_name)?;
+        storage.set_item(AUTO_CONNECT_WALLET_KEY, wallet_name)?;
+        storage.set_item(AUTO_CONNECT_ACCOUNT_KEY, address)?;
+
+        Ok(())
+    }
+
+    /// Remove the persisted wallet and account
+    pub(crate) fn forget(&self) -> WalletResult<()> {
+        let storage = self.local_storage()?;
+        storage.remove_item(RECENTLY_USED_WALLET_KEY)?;
+        storage.remove_item(AUTO_CONNECT_WALLET_KEY)?;
+        storage.remove_item(AUTO_CONNECT_ACCOUNT_KEY)?;
+
+        Ok(())
+    }
+
+    fn local_storage(&self) -> WalletResult<Storage> {
+        self.window
+            .local_storage()?
+            .ok_or(WalletError::MissingAccessToLocalStorage)
+    }
+
+    /// Called each time a wallet registers. Only the first registration of the
+    /// last connected wallet results in a silent connect request
+    pub(crate) fn on_register(&self, wallet: &Wallet) {
+        if !self.enabled() || self.attempted.get() {
+            return;
+        }
+
+        let address = match self.remembered() {
+            Ok(Some((wallet_name, address)))
+                if wallet_name.to_lowercase() == wallet.name().to_lowercase() =>
+            {
+                address
+            }
+            _ => return,
+        };
+
+        self.attempted.set(true);
+
+        let auto_connect = self.clone();
+        let wallet = wallet.clone();
+        wasm_bindgen_futures::spawn_local(async move {
+            auto_connect.reconnect(wallet, address).await;
+        });
+    }
+
+    async fn reconnect(&self, wallet: Wallet, address: Option<String>) {
+        if self.connection_info.read().await.connected_wallet().is_ok() {
+            return;
+        }
+
+        // The user is never prompted so a failure, timeout or cancellation
+        // leaves the adapter disconnected
+        let accounts = match self
+            .requests
+            .run(wallet.features.connect.call_connect(true))
+            .await
+        {
+            Ok(accounts) => accounts,
+            Err(_) => return,
+        };
+
+        let account = address
+            .and_then(|address| {
+                accounts
+                    .iter()
+                    .find(|account| account.address() == address)
+                    .cloned()
+            })
+            .unwrap_or(accounts[0].clone());
+
+        {
+            let mut connection_info = self.connection_info.write().await;
+            if connection_info.connected_wallet().is_ok() {
+                return;
+            }
+
+            connection_info
+                .set_wallet(wallet.clone())
+                .set_accounts(accounts)
+                .set_account(account.clone());
+        }
+
+        self.sender.publish(WalletEvent::Reconnected(account));
+
+        if !wallet.standard_events() {
+            return;
+        }
+
+        let outcome = wallet
+            .call_on_event(
+                self.connection_info.clone(),
+                self.storage.clone(),
+                wallet.name().to_string(),
+                self.sender.clone(),
+            )
+            .await;
+
+        if let Ok(subscription) = send_wallet_event_error()(outcome, self.sender.clone()).await {
+            self.connection_info
+                .write()
+                .await
+                .set_subscription(subscription);
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "mock")]
+#[cfg(target_arch = "wasm32")]
+mod auto_connect_tests {
+    use wasm_bindgen_test::*;
+
+    use crate::{
+        mock::test_utils::{mock_with_account, registered, sleep},
+        MockFeature, MockOutcome, Reflection, WalletAdapter, WalletEvent, AUTO_CONNECT_WALLET_KEY,
+        RECENTLY_USED_WALLET_KEY,
+    };
+
+    #[wasm_bindgen_test]
+    async fn auto_connect_reconnects_silently() {
+        let (mock, mut adapter) = registered(mock_with_account("Mock Auto Connect"));
+        adapter.enable_auto_connect();
+        adapter.connect_by_name(mock.name()).await.unwrap();
+
+        assert_eq!(
+            adapter.auto_connect().remembered().unwrap(),
+            Some((mock.name().to_string(), Some(mock.accounts()[0].address())))
+        );
+
+        // Initializing another adapter is the same as reloading the page
+        let reloaded = WalletAdapter::init().unwrap();
+        reloaded.enable_auto_connect();
+
+        let events = reloaded.subscribe_with_replay();
+        loop {
+            if let WalletEvent::Reconnected(account) = events.recv().await.unwrap() {
+                assert_eq!(account.address(), mock.accounts()[0].address());
+                break;
+            }
+        }
+
+        assert!(reloaded.is_connected().await);
+        let silent = Reflection::new(mock.calls_for(MockFeature::Connect)[1].input.clone())
+            .unwrap()
+            .reflect_inner("silent")
+            .unwrap();
+        assert_eq!(silent.as_bool(), Some(true));
+
+        reloaded.disable_auto_connect().unwrap();
+        assert_eq!(reloaded.auto_connect().remembered().unwrap(), None);
+    }
+
+    #[wasm_bindgen_test]
+    async fn recently_used_wallet_not_persisted_without_auto_connect() {
+        let (mock, mut adapter) = registered(mock_with_account("Mock Recently Used In Memory"));
+        adapter.connect_by_name(mock.name()).await.unwrap();
+
+        assert_eq!(adapter.recently_used_wallet().as_deref(), Some(mock.name()));
+        let local_storage = adapter.window().local_storage().unwrap().unwrap();
+        assert_eq!(
+            local_storage.get_item(RECENTLY_USED_WALLET_KEY).unwrap(),
+            None
+        );
+        assert_eq!(
+            local_storage.get_item(AUTO_CONNECT_WALLET_KEY).unwrap(),
+            None
+        );
+
+        // Initializing another adapter is the same as reloading the page
+        assert_eq!(WalletAdapter::init().unwrap().recently_used_wallet(), None);
+    }
+
+    #[wasm_bindgen_test]
+    async fn auto_connect_failure_stays_disconnected() {
+        let (mock, mut adapter) = registered(mock_with_account("Mock Auto Connect Rejected"));
+        adapter.enable_auto_connect();
+        adapter.connect_by_name(mock.name()).await.unwrap();
+        mock.queue_outcome(MockFeature::Connect, MockOutcome::user_rejected());
+
+        let reloaded = WalletAdapter::init().unwrap();
+        reloaded.enable_auto_connect();
+        sleep(50).await;
+
+        assert_eq!(mock.calls_for(MockFeature::Connect).len(), 2);
+        assert!(!reloaded.is_connected().await);
+
+        reloaded.disable_auto_connect().unwrap();
+    }
+}
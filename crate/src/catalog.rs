@@ -0,0 +1,159 @@
+use wallet_adapter_common::{KnownWallet, WalletReadiness};
+
+use crate::Wallet;
+
+/// A wallet rendered by a wallet picker, merging a registered [Wallet]
+/// with its [KnownWallet] entry in the [crate::WalletCatalog]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WalletListing {
+    readiness: WalletReadiness,
+    wallet: Option<Wallet>,
+    known_wallet: Option<KnownWallet>,
+}
+
+impl WalletListing {
+    /// Merge a registered `wallet` and its `known_wallet` entry.
+    /// `mobile` is used to determine if a wallet that is not registered is [WalletReadiness::Loadable]
+    pub fn new(wallet: Option<Wallet>, known_wallet: Option<KnownWallet>, mobile: bool) -> Self {
+        Self {
+            readiness: WalletReadiness::new(wallet.is_some(), mobile, known_wallet.as_ref()),
+            wallet,
+            known_wallet,
+        }
+    }
+
+    /// Get the name of the registered wallet, otherwise the name in the catalog
+    pub fn name(&self) -> &str {
+        self.wallet
+            .as_ref()
+            .map(|wallet| wallet.name())
+            .or(self.known_wallet.as_ref().map(|known| known.name()))
+            .unwrap_or_default()
+    }
+
+    /// Get the icon of the registered wallet, otherwise the icon in the catalog
+    pub fn icon(&self) -> Option<&str> {
+        self.wallet
+            .as_ref()
+            .and_then(|wallet| wallet.icon().map(|icon| icon.as_ref()))
+            .or(self.known_wallet.as_ref().and_then(|known| known.icon()))
+    }
+
+    /// Get the readiness of the wallet
+    pub fn readiness(&self) -> WalletReadiness {
+        self.readiness
+    }
+
+    /// Get the registered wallet if the wallet is [WalletReadiness::Installed]
+    pub fn wallet(&self) -> Option<&Wallet> {
+        self.wallet.as_ref()
+    }
+
+    /// Get the entry of the wallet in the catalog
+    pub fn known_wallet(&self) -> Option<&KnownWallet> {
+        self.known_wallet.as_ref()
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "mock")]
+#[cfg(target_arch = "wasm32")]
+mod catalog_tests {
+    use wasm_bindgen_test::*;
+
+    use crate::{
+        mock::test_utils::mock_with_account, ChainSupport, KnownWallet, Wallet, WalletAdapter,
+        WalletListOptions, WalletReadiness,
+    };
+
+    #[wasm_bindgen_test]
+    async fn ranks_wallets_in_registration_order() {
+        let names = ["Mock Rank Zulu", "Mock Rank Alpha", "Mock Rank Mike"];
+        let mut adapter = WalletAdapter::init().unwrap();
+        names.iter().for_each(|name| {
+            mock_with_account(name)
+                .set_chains(&["solana:devnet"])
+                .register(adapter.window())
+                .unwrap();
+        });
+
+        let position = |wallets: &[Wallet], name: &str| {
+            wallets
+                .iter()
+                .position(|wallet| wallet.name() == name)
+                .unwrap()
+        };
+        let wallets = adapter.wallets();
+        assert!(position(&wallets, names[0]) < position(&wallets, names[1]));
+        assert!(position(&wallets, names[1]) < position(&wallets, names[2]));
+
+        adapter.connect_by_name(names[2]).await.unwrap();
+        assert_eq!(adapter.recently_used_wallet().as_deref(), Some(names[2]));
+
+        let mut options = WalletListOptions::new();
+        options.add_preferred(names[1]);
+        let ranked = adapter.ranked_wallets(&options);
+        assert_eq!(ranked[0].name(), names[2]);
+        assert_eq!(ranked[1].name(), names[1]);
+
+        options.require_chains(ChainSupport {
+            mainnet: true,
+            ..Default::default()
+        });
+        assert!(adapter
+            .ranked_wallets(&options)
+            .iter()
+            .all(|wallet| !names.contains(&wallet.name())));
+    }
+
+    #[wasm_bindgen_test]
+    fn lists_catalog_wallets_with_readiness() {
+        let mut adapter = WalletAdapter::init().unwrap();
+        adapter
+            .add_known_wallet(
+                KnownWallet::new(
+                    "Mock Catalog Installed",
+                    Option::None,
+                    "https://mock.dev",
+                    "https://mock.dev/install",
+                )
+                .set_icon("https://mock.dev/icon.svg"),
+            )
+            .add_known_wallet(KnownWallet::new(
+                "Mock Catalog Missing",
+                Option::None,
+                "https://mock.dev",
+                "https://mock.dev/install",
+            ))
+            .remove_known_wallet("Backpack");
+        mock_with_account("Mock Catalog Installed")
+            .set_icon("data:image/svg+xml;base64,bW9jaw==")
+            .register(adapter.window())
+            .unwrap();
+
+        let listings = adapter.wallet_listings(&WalletListOptions::new());
+        let listing = |name: &str| {
+            listings
+                .iter()
+                .position(|listing| listing.name() == name)
+                .map(|position| (position, &listings[position]))
+        };
+
+        let (installed_position, installed) = listing("Mock Catalog Installed").unwrap();
+        assert_eq!(installed.readiness(), WalletReadiness::Installed);
+        assert_eq!(installed.icon(), Some("data:image/svg+xml;base64,bW9jaw=="));
+        assert!(installed.known_wallet().is_some());
+
+        let (missing_position, missing) = listing("Mock Catalog Missing").unwrap();
+        assert_eq!(missing.readiness(), WalletReadiness::NotDetected);
+        assert!(missing.wallet().is_none());
+        assert_eq!(
+            missing.known_wallet().unwrap().install_url(),
+            "https://mock.dev/install"
+        );
+        assert!(installed_position < missing_position);
+
+        assert!(listing("Phantom").is_some());
+        assert!(listing("Backpack").is_none());
+    }
+}
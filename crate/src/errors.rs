@@ -21,6 +21,17 @@ pub enum WalletError {
     /// Unable to send the a [WalletEvent] via the [crate::WalletEventSender]
     #[error("Unable to send the a `WalletEvent` variant via the WalletEventSender channel")]
     ChannelError,
+    /// The wallet did not settle a request within the timeout set using
+    /// [crate::WalletAdapter::set_request_timeout]
+    #[error("The wallet did not respond to the request before the request timed out")]
+    Timeout,
+    /// The request was abandoned using [crate::AdapterCancelHandle::cancel]
+    #[error("The request to the wallet was cancelled")]
+    RequestCancelled,
+    /// A [crate::WalletEventSubscription] fell behind and the given number of
+    /// its oldest events were dropped
+    #[error("The subscription to wallet events fell behind and `{0}` events were dropped")]
+    EventsLagged(u64),
     /// An JavaScript Error corresponding to a [JsValue] .
     /// It contains the error type represented by `name`,
     /// the error message `message`
@@ -54,15 +65,27 @@ pub enum WalletError {
     /// Unable to access browser document
     #[error("Unable to access browser document")]
     MissingAccessToBrowserDocument,
+    /// Unable to access the `localStorage` of the browser window
+    #[error("Unable to access the `localStorage` of the browser window")]
+    MissingAccessToLocalStorage,
     /// Only `processed`, `confirmed` and `finalized` commitments are supported by Solana clusters
     #[error("Unsupported Commitment level `{0}`. Only `processed`, `confirmed` and `finalized` commitments are supported by Solana clusters")]
     UnsupportedCommitment(String),
+    /// Only `parallel` and `serial` modes are supported when sending transactions
+    #[error("Unsupported send mode `{0}`. Only `parallel` and `serial` modes are supported")]
+    UnsupportedSendMode(String),
     /// The wallet version is invalid, expected SemVer version
     #[error("The wallet version `{0}` is invalid, expected SemVer version")]
     InvalidWalletVersion(String),
     /// Unexpected SemVer number to parse to a `u8`
+    #[deprecated(
+        note = "no longer returned since SemVer numbers are parsed to a `u64`, see `WalletError::InvalidWalletVersion`"
+    )]
     #[error("Unexpected SemVer number `{0}` to parse to a `u8`")]
     InvalidSemVerNumber(String),
+    /// The SemVer version requirement is invalid
+    #[error("`{0}` is not a valid SemVer version requirement")]
+    InvalidSemverRequirement(String),
     /// The byte length should be equal to 32 bytes in length
     #[error("The byte length should be equal to 32 bytes in length")]
     Expected32ByteLength,
@@ -82,6 +105,18 @@ pub enum WalletError {
     /// Legacy transaction versions need to be supported yet the encountered wallet does not do this.
     #[error("Legacy transaction versions need to be supported yet the encountered wallet does not do this.")]
     LegacyTransactionSupportRequired,
+    /// The version of the transaction is not listed in the `supportedTransactionVersions` of the wallet
+    #[error("The wallet does not support `{0}` transactions")]
+    TransactionVersionNotSupported(String),
+    /// The bytes are not a transaction serialized in the wire format
+    #[error("The bytes are not a transaction serialized in the wire format")]
+    InvalidTransactionBytes,
+    /// Legacy messages cannot load accounts from address lookup tables
+    #[error("A legacy message cannot contain address table lookups")]
+    LegacyMessageWithAddressTableLookups,
+    /// The chain identifier is not in the CAIP-2 `namespace:reference` format
+    #[error("The chain identifier `{0}` is not in the `namespace:reference` format")]
+    InvalidChainId(String),
     /// The blockchain encountered is not supported.
     #[error("The blockchain `{0}` is not supported")]
     UnsupportedChain(String),
@@ -96,6 +131,9 @@ pub enum WalletError {
         "Attempted to connect to an account that does not exist or might have been disconnected"
     )]
     AccountNotFound,
+    /// The connected wallet has not authorized the app to use the account with this address
+    #[error("The connected wallet has not authorized the app to use the account `{0}`")]
+    AccountNotAuthorized(String),
     /// Unable to connect to a wallet. The user may have rejected the request
     #[error("Unable to connect to a wallet. Error `{0}` request")]
     WalletConnectError(String),
@@ -168,9 +206,44 @@ pub enum WalletError {
     /// The `solana:signTransaction` function is missing in the provided wallet
     #[error("The `solana:signTransaction` function is missing in the provided wallet")]
     MissingSignTransactionFunction,
+    /// The wallet did not register a `signAndSendAllTransactions` function for `solana:signAndSendAllTransactions` namespace
+    #[error("The wallet did not register a `signAndSendAllTransactions` function for `solana:signAndSendAllTransactions` namespace")]
+    MissingSignAndSendAllTransactionsFunction,
     /// The `sendAndSignTransaction` method did not return any signature
     #[error("The `sendAndSignTransaction` method did not return any signature")]
     SendAndSignTransactionSignatureEmpty,
+    /// The number of signed transactions returned by the wallet
+    /// does not match the number of transactions sent
+    #[error("Expected `{expected}` signed transactions from the wallet but received `{received}`")]
+    SignedTransactionsCountMismatch {
+        /// The number of transactions sent to the wallet
+        expected: usize,
+        /// The number of signed transactions returned by the wallet
+        received: usize,
+    },
+    /// The message of a transaction signed by the wallet is different from the message sent to be signed
+    #[error("The message of the transaction signed by the wallet is different from the message sent to be signed")]
+    SignedTransactionMismatch,
+    /// The connected account is not one of the signers of the transaction
+    #[error("The account `{0}` is not a signer of the transaction")]
+    AccountNotTransactionSigner(String),
+    /// The signature of the connected account in the signed transaction is not valid for the message
+    #[error("The signature of the account `{0}` in the signed transaction is not valid")]
+    InvalidTransactionSignature(String),
+    /// The wallet does not have the extension feature
+    #[error("The wallet does not have the feature `{0}`")]
+    MissingExtensionFeature(String),
+    /// The extension feature does not have a function with the given method name
+    #[error("The feature `{feature}` does not have the function `{method}`")]
+    MissingExtensionMethod {
+        /// The identifier of the feature
+        feature: String,
+        /// The name of the method
+        method: String,
+    },
+    /// The parsed feature is not of the type of the requested [FeatureParser](crate::FeatureParser)
+    #[error("The parsed feature `{0}` is not of the type of the requested feature parser")]
+    FeatureParserMismatch(String),
     /// Overflow during SystemTime::checked_add(expiration_time_milliseconds) overflow
     #[error("SystemTime::checked_add(expiration_time_milliseconds) overflow")]
     SystemTimeCheckedAddOverflow,
@@ -240,6 +313,19 @@ impl From<WalletUtilsError> for WalletError {
             WalletUtilsError::Expected32ByteLength => Self::Expected32ByteLength,
             WalletUtilsError::NonceMustBeAtLeast8Characters => Self::NonceMustBeAtLeast8Characters,
             WalletUtilsError::MessageResponseMismatch => Self::MessageResponseMismatch,
+            WalletUtilsError::InvalidTransactionBytes => Self::InvalidTransactionBytes,
+            WalletUtilsError::TransactionVersionNotSupported(version) => {
+                Self::TransactionVersionNotSupported(version)
+            }
+            WalletUtilsError::LegacyMessageWithAddressTableLookups => {
+                Self::LegacyMessageWithAddressTableLookups
+            }
+            WalletUtilsError::UnsupportedChain(chain) => Self::UnsupportedChain(chain),
+            WalletUtilsError::InvalidChainId(chain) => Self::InvalidChainId(chain),
+            WalletUtilsError::InvalidSemverVersion(version) => Self::InvalidWalletVersion(version),
+            WalletUtilsError::InvalidSemverRequirement(requirement) => {
+                Self::InvalidSemverRequirement(requirement)
+            }
         }
     }
 }
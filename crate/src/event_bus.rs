@@ -0,0 +1,247 @@
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+};
+
+use async_channel::{bounded, Receiver, Sender};
+
+use crate::{WalletError, WalletEvent, WalletEventReceiver, WalletResult};
+
+/// Broadcasts every [WalletEvent] to all subscribers.
+/// Publishing never waits for a subscriber, once a subscriber falls `capacity` events
+/// behind its oldest event is dropped and [WalletEventSubscription::recv]
+/// returns [WalletError::EventsLagged]
+#[derive(Debug, Clone)]
+pub struct WalletEventBus(Rc<RefCell<EventBusState>>);
+
+#[derive(Debug)]
+struct EventBusState {
+    capacity: usize,
+    // The channel shared by all the receivers of the deprecated [crate::WalletAdapter::events]
+    shared: (Sender<WalletEvent>, Receiver<WalletEvent>),
+    subscribers: Vec<(Sender<WalletEvent>, Rc<Cell<u64>>)>,
+    latest_state: WalletEvent,
+}
+
+impl WalletEventBus {
+    /// Instantiate a bus where each subscriber buffers up to `capacity` events
+    pub fn new(capacity: usize) -> Self {
+        Self(Rc::new(RefCell::new(EventBusState {
+            capacity,
+            shared: bounded(capacity),
+            subscribers: Vec::default(),
+            latest_state: WalletEvent::Disconnected,
+        })))
+    }
+
+    /// Send the `event` to every subscriber and to the receivers of
+    /// the deprecated [crate::WalletAdapter::events]
+    pub fn publish(&self, event: WalletEvent) {
+        let mut state = self.0.borrow_mut();
+
+        if matches!(
+            event,
+            WalletEvent::Connected(_)
+                | WalletEvent::Reconnected(_)
+                | WalletEvent::AccountChanged(_)
+                | WalletEvent::Disconnected
+        ) {
+            state.latest_state = event.clone();
+        }
+
+        // Subscribers whose subscriptions were dropped are removed
+        state.subscribers.retain(|(sender, _)| !sender.is_closed());
+        state.subscribers.iter().for_each(|(sender, lagged)| {
+            if let Ok(Some(_)) = sender.force_send(event.clone()) {
+                lagged.set(lagged.get() + 1);
+            }
+        });
+
+        let _ = state.shared.0.force_send(event);
+    }
+
+    /// Subscribe to all the events published after subscribing
+    pub fn subscribe(&self) -> WalletEventSubscription {
+        self.add_subscriber(false)
+    }
+
+    /// Same as [Self::subscribe] but the first event received is the latest
+    /// [WalletEvent::Connected], [WalletEvent::Reconnected], [WalletEvent::AccountChanged]
+    /// or [WalletEvent::Disconnected] event, which is [WalletEvent::Disconnected]
+    /// if no wallet has connected yet
+    pub fn subscribe_with_replay(&self) -> WalletEventSubscription {
+        self.add_subscriber(true)
+    }
+
+    /// The number of subscriptions that have not been dropped
+    pub fn subscriber_count(&self) -> usize {
+        self.0
+            .borrow()
+            .subscribers
+            .iter()
+            .filter(|(sender, _)| !sender.is_closed())
+            .count()
+    }
+
+    fn add_subscriber(&self, replay: bool) -> WalletEventSubscription {
+        let mut state = self.0.borrow_mut();
+
+        let (sender, receiver) = bounded(state.capacity);
+        if replay {
+            let _ = sender.force_send(state.latest_state.clone());
+        }
+
+        let lagged = Rc::<Cell<u64>>::default();
+        state.subscribers.push((sender, lagged.clone()));
+
+        WalletEventSubscription { receiver, lagged }
+    }
+
+    /// The receiver shared by all the clones returned by the deprecated
+    /// [crate::WalletAdapter::events], each event is received by only one of the clones
+    pub(crate) fn receiver(&self) -> WalletEventReceiver {
+        self.0.borrow().shared.1.clone()
+    }
+}
+
+/// A subscription to the [WalletEventBus] that receives every event.
+/// Dropping the subscription unsubscribes
+#[derive(Debug)]
+pub struct WalletEventSubscription {
+    receiver: Receiver<WalletEvent>,
+    lagged: Rc<Cell<u64>>,
+}
+
+impl WalletEventSubscription {
+    /// Wait for the next event. Returns [WalletError::EventsLagged] with the number
+    /// of events dropped since the last call if the subscription fell behind,
+    /// the next call then returns the oldest event that was kept
+    pub async fn recv(&self) -> WalletResult<WalletEvent> {
+        let lagged = self.lagged.take();
+        if lagged > 0 {
+            return Err(WalletError::EventsLagged(lagged));
+        }
+
+        self.receiver
+            .recv()
+            .await
+            .map_err(|_| WalletError::ChannelError)
+    }
+
+    /// The number of events waiting to be received
+    pub fn len(&self) -> usize {
+        self.receiver.len()
+    }
+
+    /// Check whether there are no events waiting to be received
+    pub fn is_empty(&self) -> bool {
+        self.receiver.is_empty()
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "mock")]
+#[cfg(target_arch = "wasm32")]
+mod event_bus_wasm_tests {
+    use wasm_bindgen_test::*;
+
+    use crate::{
+        mock::test_utils::{mock_with_account, registered},
+        WalletError, WalletEvent,
+    };
+
+    #[wasm_bindgen_test]
+    async fn broadcasts_events_to_subscribers() {
+        let (mock, mut adapter) = registered(mock_with_account("Mock Broadcast"));
+        let header = adapter.subscribe();
+        let dashboard = adapter.subscribe();
+
+        let account = adapter.connect_by_name(mock.name()).await.unwrap();
+        for subscription in [&header, &dashboard] {
+            assert_eq!(
+                subscription.recv().await,
+                Ok(WalletEvent::Connected(account.clone()))
+            );
+        }
+
+        let replayed = adapter.subscribe_with_replay();
+        assert_eq!(
+            replayed.recv().await,
+            Ok(WalletEvent::Connected(account.clone()))
+        );
+
+        drop(dashboard);
+        let bus = adapter.events_sender();
+        (0..7).for_each(|_| bus.publish(WalletEvent::Skip));
+        assert_eq!(bus.subscriber_count(), 2);
+
+        assert_eq!(header.recv().await, Err(WalletError::EventsLagged(2)));
+        assert_eq!(header.len(), 5);
+        assert_eq!(header.recv().await, Ok(WalletEvent::Skip));
+
+        adapter.disconnect().await.unwrap();
+        assert_eq!(
+            adapter.subscribe_with_replay().recv().await,
+            Ok(WalletEvent::Disconnected)
+        );
+    }
+}
+
+#[cfg(test)]
+mod event_bus_tests {
+    use super::*;
+    use crate::block_on;
+
+    fn unregistered(wallet_name: &str) -> WalletEvent {
+        WalletEvent::WalletUnregistered(wallet_name.to_string())
+    }
+
+    #[test]
+    fn reports_lagged_events() {
+        let bus = WalletEventBus::new(2);
+        let subscription = bus.subscribe();
+
+        ["A", "B", "C", "D", "E"]
+            .into_iter()
+            .for_each(|wallet_name| bus.publish(unregistered(wallet_name)));
+        assert_eq!(subscription.len(), 2);
+
+        assert_eq!(
+            block_on(subscription.recv()),
+            Err(WalletError::EventsLagged(3))
+        );
+        assert_eq!(block_on(subscription.recv()), Ok(unregistered("D")));
+        assert_eq!(block_on(subscription.recv()), Ok(unregistered("E")));
+        assert!(subscription.is_empty());
+    }
+
+    #[test]
+    fn delivers_to_every_subscriber() {
+        let bus = WalletEventBus::new(5);
+        let first = bus.subscribe();
+        let second = bus.subscribe();
+        assert_eq!(bus.subscriber_count(), 2);
+
+        bus.publish(unregistered("A"));
+        assert_eq!(block_on(first.recv()), Ok(unregistered("A")));
+        assert_eq!(block_on(second.recv()), Ok(unregistered("A")));
+
+        drop(second);
+        assert_eq!(bus.subscriber_count(), 1);
+    }
+
+    #[test]
+    fn replays_latest_state() {
+        let bus = WalletEventBus::new(5);
+        assert_eq!(
+            block_on(bus.subscribe_with_replay().recv()),
+            Ok(WalletEvent::Disconnected)
+        );
+
+        // Only connection state changes are replayed
+        bus.publish(unregistered("A"));
+        let subscription = bus.subscribe_with_replay();
+        assert_eq!(block_on(subscription.recv()), Ok(WalletEvent::Disconnected));
+        assert!(subscription.is_empty());
+    }
+}
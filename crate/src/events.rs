@@ -1,44 +1,68 @@
-use std::rc::Rc;
+use std::{cell::RefCell, rc::Rc};
 
-use async_channel::{Receiver, Sender};
+use async_channel::Receiver;
 use wallet_adapter_common::standardized_events::{
     WINDOW_APP_READY_EVENT_TYPE, WINDOW_REGISTER_WALLET_EVENT_TYPE,
 };
 use web_sys::{
-    js_sys::{Object, Reflect},
+    js_sys::{Function, Object, Reflect},
     wasm_bindgen::{prelude::Closure, JsValue},
     CustomEvent, CustomEventInit, Window,
 };
 
 use crate::{
-    InnerUtils, Reflection, StorageType, Wallet, WalletAccount, WalletAdapter, WalletError,
-    WalletResult,
+    AutoConnect, ConnectionInfoInner, InnerUtils, Reflection, StorageType, Wallet, WalletAccount,
+    WalletAdapter, WalletError, WalletEventBus, WalletResult,
 };
 
-/// The `Sender` part of an [async_channel::bounded] channel
-pub type WalletEventSender = Sender<WalletEvent>;
+/// Publishes [WalletEvent]s to all subscribers, see [WalletEventBus]
+pub type WalletEventSender = WalletEventBus;
 
 /// The `Receiver` part of an [async_channel::bounded] channel
 pub type WalletEventReceiver = Receiver<WalletEvent>;
 
 /// Used to initialize the `Register` and `AppReady` events to the browser window
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug)]
 pub struct InitEvents<'a> {
     window: &'a Window,
+    adapter: Option<RegisterContext>,
+}
+
+/// The parts of the [WalletAdapter] updated when a wallet registers or unregisters
+#[derive(Debug, Clone)]
+struct RegisterContext {
+    auto_connect: AutoConnect,
+    sender: WalletEventSender,
+    connection_info: ConnectionInfoInner,
+    // Send [WalletEvent::WalletRegistered] and registration errors as events
+    announce: bool,
 }
 
 impl<'a> InitEvents<'a> {
     /// Instantiate [InitEvents]
     pub fn new(window: &'a Window) -> Self {
-        Self { window }
+        Self {
+            window,
+            adapter: Option::None,
+        }
     }
 
     /// Register events by providing a [crate::WalletStorage] that is used to store
     /// all registered wallets
     pub fn init(&self, adapter: &mut WalletAdapter) -> WalletResult<()> {
+        let init_events = Self {
+            window: self.window,
+            adapter: Some(RegisterContext {
+                auto_connect: adapter.auto_connect().clone(),
+                sender: adapter.events_sender(),
+                connection_info: adapter.connection_info_inner(),
+                announce: true,
+            }),
+        };
+
         let storage = adapter.storage();
-        self.register_wallet_event(storage.clone_inner())?;
-        self.dispatch_app_event(storage.clone_inner());
+        init_events.register_wallet_event(storage.clone_inner())?;
+        init_events.dispatch_app_event(storage.clone_inner());
 
         Ok(())
     }
@@ -49,7 +73,15 @@ impl<'a> InitEvents<'a> {
         app_ready_init.set_bubbles(false);
         app_ready_init.set_cancelable(false);
         app_ready_init.set_composed(false);
-        app_ready_init.set_detail(&Self::register_object(storage));
+        // Wallets registered on app ready are available once the adapter is initialized
+        // so they are not sent as events
+        app_ready_init.set_detail(&Self::register_object_with_adapter(
+            storage,
+            self.adapter.clone().map(|adapter| RegisterContext {
+                announce: false,
+                ..adapter
+            }),
+        ));
 
         let app_ready_ev =
             CustomEvent::new_with_event_init_dict(WINDOW_APP_READY_EVENT_TYPE, &app_ready_init)
@@ -61,6 +93,7 @@ impl<'a> InitEvents<'a> {
     /// The register wallet event registered to the browser window
     pub fn register_wallet_event(&self, storage: StorageType) -> WalletResult<()> {
         let inner_storage = Rc::clone(&storage);
+        let adapter = self.adapter.clone();
 
         let listener_closure = Closure::wrap(Box::new(move |custom_event: CustomEvent| {
             let detail = Reflection::new(custom_event
@@ -69,7 +102,7 @@ impl<'a> InitEvents<'a> {
 
             InnerUtils::jsvalue_to_error(detail.call1(
                 &JsValue::null(),
-                &Self::register_object(inner_storage.clone()),
+                &Self::register_object_with_adapter(inner_storage.clone(), adapter.clone()),
             ))
             .unwrap()
         }) as Box<dyn Fn(_)>);
@@ -85,32 +118,128 @@ impl<'a> InitEvents<'a> {
         Ok(())
     }
 
+    /// Announce a wallet to apps using the wallet standard. This dispatches the
+    /// `wallet-standard:register-wallet` event and listens for the `wallet-standard:app-ready`
+    /// event in case an app is initialized after the wallet is registered.
+    /// The `wallet` is the JavaScript object passed to the `register` function of the app.
+    /// The returned [WalletUnregister] unregisters the wallet from all the apps it registered with
+    pub fn register_wallet(&self, wallet: &JsValue) -> WalletResult<WalletUnregister> {
+        let unregister = WalletUnregister {
+            window: Some(self.window.clone()),
+            ..Default::default()
+        };
+
+        let wallet_inner = wallet.clone();
+        let unregister_inner = unregister.clone();
+        let register_callback = Closure::wrap(Box::new(move |api: JsValue| {
+            Self::call_register(&api, &wallet_inner, &unregister_inner);
+        }) as Box<dyn Fn(_)>);
+
+        let register_init = CustomEventInit::new();
+        register_init.set_bubbles(false);
+        register_init.set_cancelable(false);
+        register_init.set_composed(false);
+        register_init.set_detail(&register_callback.into_js_value());
+
+        let register_event = CustomEvent::new_with_event_init_dict(
+            WINDOW_REGISTER_WALLET_EVENT_TYPE,
+            &register_init,
+        )?;
+
+        let wallet_inner = wallet.clone();
+        let unregister_inner = unregister.clone();
+        let app_ready_listener = Closure::wrap(Box::new(move |app_ready_event: CustomEvent| {
+            Self::call_register(&app_ready_event.detail(), &wallet_inner, &unregister_inner);
+        }) as Box<dyn Fn(_)>);
+
+        let app_ready_listener =
+            Reflection::new(app_ready_listener.into_js_value())?.into_function()?;
+
+        self.window
+            .add_event_listener_with_callback(WINDOW_APP_READY_EVENT_TYPE, &app_ready_listener)?;
+        unregister
+            .app_ready_listener
+            .borrow_mut()
+            .replace(app_ready_listener);
+        self.window.dispatch_event(&register_event)?;
+
+        Ok(unregister)
+    }
+
+    fn call_register(api: &JsValue, wallet: &JsValue, unregister: &WalletUnregister) {
+        let outcome = Reflection::new(api.clone())
+            .and_then(|api| api.get_function("register"))
+            .and_then(|register| Ok(register.call1(&JsValue::null(), wallet)?))
+            .and_then(|unregister_fn| Reflection::new(unregister_fn)?.into_function());
+
+        match outcome {
+            Ok(unregister_fn) => unregister.functions.borrow_mut().push(unregister_fn),
+            Err(error) => web_sys::console::error_2(
+                &"WALLET REGISTER ERROR".into(),
+                &error.to_string().into(),
+            ),
+        }
+    }
+
     /// Sets the object to be passed to the register function
     pub fn register_object(storage: StorageType) -> Object {
-        // The `register` function that logs and returns a closure like in your JS code
-        let register =
-            Closure::wrap(
-                Box::new(move |value: JsValue| match Wallet::from_jsvalue(value) {
-                    Ok(wallet) => {
-                        let inner_outcome = storage.clone();
-
-                        inner_outcome.borrow_mut().insert(
-                            blake3::hash(wallet.name().to_lowercase().as_bytes()),
-                            wallet,
-                        );
+        Self::register_object_with_adapter(storage, Option::None)
+    }
+
+    /// The `register` function stores the wallet and returns the `unregister` function
+    /// that removes it. Registered wallets and registration errors are sent as
+    /// [WalletEvent::WalletRegistered] and [WalletEvent::BackgroundTaskError] if
+    /// the `adapter` announces them, otherwise the errors are logged to the browser console
+    fn register_object_with_adapter(
+        storage: StorageType,
+        adapter: Option<RegisterContext>,
+    ) -> Object {
+        let register = Closure::wrap(Box::new(move |value: JsValue| {
+            let announce_to = adapter
+                .as_ref()
+                .filter(|adapter| adapter.announce)
+                .map(|adapter| &adapter.sender);
+
+            match Wallet::from_jsvalue(value) {
+                Ok(mut wallet) => {
+                    storage.borrow().parse_features(&mut wallet);
+
+                    if let Some(adapter) = adapter.as_ref() {
+                        adapter.auto_connect.on_register(&wallet);
                     }
-                    Err(error) => {
-                        let error = error.to_string();
-                        if error.contains("is not supported") {
-                        } else {
-                            web_sys::console::error_2(
-                                &"REGISTER EVENT ERROR".into(),
-                                &error.into(),
-                            );
-                        }
+
+                    let registration_id = {
+                        let mut storage = storage.borrow_mut();
+                        storage.insert(wallet.clone());
+
+                        storage.registration_id(wallet.name())
+                    };
+
+                    let unregister = Self::unregister_fn(
+                        storage.clone(),
+                        adapter.clone(),
+                        wallet.name(),
+                        registration_id,
+                    );
+
+                    Self::send_event(announce_to, WalletEvent::WalletRegistered(Box::new(wallet)));
+
+                    unregister
+                }
+                Err(error) => {
+                    if announce_to.is_none() {
+                        web_sys::console::error_2(
+                            &"REGISTER EVENT ERROR".into(),
+                            &error.to_string().into(),
+                        );
                     }
-                }) as Box<dyn Fn(_)>,
-            );
+
+                    Self::send_event(announce_to, WalletEvent::BackgroundTaskError(error));
+
+                    Closure::wrap(Box::new(|| {}) as Box<dyn Fn()>).into_js_value()
+                }
+            }
+        }) as Box<dyn Fn(_) -> JsValue>);
 
         // Create an object and set the `register` property
         let register_object = Object::new();
@@ -125,6 +254,88 @@ impl<'a> InitEvents<'a> {
 
         register_object
     }
+
+    // Removes the wallet from storage if it has not been replaced by another registration.
+    // The adapter is disconnected if the wallet is the connected wallet
+    fn unregister_fn(
+        storage: StorageType,
+        adapter: Option<RegisterContext>,
+        wallet_name: &str,
+        registration_id: Option<u64>,
+    ) -> JsValue {
+        let wallet_name = wallet_name.to_string();
+
+        Closure::wrap(Box::new(move || {
+            let mut storage = storage.borrow_mut();
+
+            if storage.registration_id(&wallet_name) != registration_id {
+                return;
+            }
+
+            storage.remove(&wallet_name);
+
+            if let Some(adapter) = adapter.clone() {
+                let wallet_name = wallet_name.clone();
+
+                wasm_bindgen_futures::spawn_local(async move {
+                    let mut connection_info = adapter.connection_info.write().await;
+
+                    let is_connected =
+                        connection_info
+                            .connected_wallet_raw()
+                            .is_some_and(|connected| {
+                                connected.name().to_lowercase() == wallet_name.to_lowercase()
+                            });
+
+                    if is_connected {
+                        connection_info
+                            .set_disconnected(adapter.sender.clone())
+                            .await;
+                    }
+                    drop(connection_info);
+
+                    adapter
+                        .sender
+                        .publish(WalletEvent::WalletUnregistered(wallet_name));
+                });
+            }
+        }) as Box<dyn Fn()>)
+        .into_js_value()
+    }
+
+    fn send_event(sender: Option<&WalletEventSender>, event: WalletEvent) {
+        if let Some(sender) = sender {
+            sender.publish(event);
+        }
+    }
+}
+
+/// The `unregister` functions returned by the apps a wallet registered with
+/// using [InitEvents::register_wallet]
+#[derive(Debug, Clone, Default)]
+pub struct WalletUnregister {
+    window: Option<Window>,
+    functions: Rc<RefCell<Vec<Function>>>,
+    app_ready_listener: Rc<RefCell<Option<Function>>>,
+}
+
+impl WalletUnregister {
+    /// Unregister the wallet from all the apps it registered with
+    /// and stop registering with apps initialized later
+    pub fn unregister(&self) -> WalletResult<()> {
+        if let (Some(window), Some(listener)) =
+            (self.window.as_ref(), self.app_ready_listener.take())
+        {
+            window.remove_event_listener_with_callback(WINDOW_APP_READY_EVENT_TYPE, &listener)?;
+        }
+
+        self.functions
+            .take()
+            .iter()
+            .try_for_each(|unregister| unregister.call0(&JsValue::null()).map(|_| ()))?;
+
+        Ok(())
+    }
 }
 
 /// Events emitted by connected browser extensions
@@ -148,6 +359,17 @@ pub enum WalletEvent {
     /// `on` method from the `[standard:events]` namespace
     /// (when an account is connected, changed or disconnected)
     BackgroundTaskError(WalletError),
+    /// A wallet registered itself with the adapter, example a browser
+    /// extension that finished injecting after the adapter was initialized
+    WalletRegistered(Box<Wallet>),
+    /// A wallet with the given name was unregistered from the adapter
+    WalletUnregistered(String),
+    /// The connected wallet emitted a `change` event with different `chains`.
+    /// Contains the updated wallet
+    ChainsChanged(Box<Wallet>),
+    /// The connected wallet emitted a `change` event with different `features`.
+    /// Contains the updated wallet
+    FeaturesChanged(Box<Wallet>),
     /// An event was emitted by a wallet that is not connected.
     #[default]
     Skip,
@@ -161,8 +383,135 @@ impl core::fmt::Display for WalletEvent {
             Self::Disconnected => "Disconnected",
             Self::AccountChanged(_) => "Account Changed",
             Self::BackgroundTaskError(error) => &format!("Task error: {error:?}"),
+            Self::WalletRegistered(wallet) => &format!("Wallet Registered: {}", wallet.name()),
+            Self::WalletUnregistered(name) => &format!("Wallet Unregistered: {name}"),
+            Self::ChainsChanged(wallet) => &format!("Chains Changed: {}", wallet.name()),
+            Self::FeaturesChanged(wallet) => &format!("Features Changed: {}", wallet.name()),
             Self::Skip => "Skipped",
         };
         write!(f, "{as_str}")
     }
 }
+
+#[cfg(test)]
+#[cfg(feature = "mock")]
+#[cfg(target_arch = "wasm32")]
+mod init_events_tests {
+    use wasm_bindgen_test::*;
+
+    use crate::{
+        mock::test_utils::{mock_with_account, registered},
+        WalletAdapter, WalletError, WalletEvent,
+    };
+
+    #[wasm_bindgen_test]
+    fn registers_with_register_wallet_event() {
+        let (mock, adapter) = registered(mock_with_account("Mock Register"));
+
+        let wallet = adapter.get_wallet(mock.name()).unwrap();
+        assert_eq!(wallet.name(), "Mock Register");
+        assert_eq!(wallet.chains().len(), 4);
+        assert_eq!(wallet.accounts().len(), 1);
+        assert_eq!(wallet.accounts()[0].address(), mock.accounts()[0].address());
+        assert_eq!(
+            wallet.accounts()[0].label(),
+            Some(&"Mock Account".to_string())
+        );
+        assert!(wallet.standard_connect());
+        assert!(wallet.standard_disconnect());
+        assert!(wallet.standard_events());
+        assert!(wallet.solana_signin());
+        assert!(wallet.solana_sign_message());
+        assert!(wallet.solana_sign_transaction());
+        assert!(wallet.solana_sign_and_send_transaction());
+    }
+
+    #[wasm_bindgen_test]
+    async fn sends_registration_events() {
+        let adapter = WalletAdapter::init().unwrap();
+        let events = adapter.subscribe();
+
+        let mock = mock_with_account("Mock Registration Event");
+        mock.register(adapter.window()).unwrap();
+        match events.recv().await {
+            Ok(WalletEvent::WalletRegistered(wallet)) => assert_eq!(wallet.name(), mock.name()),
+            event => panic!("Expected a registration event, got {event:?}"),
+        }
+
+        mock_with_account("Mock Registration Error")
+            .set_version("1.0")
+            .register(adapter.window())
+            .unwrap();
+        assert_eq!(
+            events.recv().await,
+            Ok(WalletEvent::BackgroundTaskError(
+                WalletError::InvalidWalletVersion("1.0".to_string())
+            ))
+        );
+        assert!(adapter.get_wallet("Mock Registration Error").is_err());
+    }
+
+    #[wasm_bindgen_test]
+    async fn unregisters_connected_wallet() {
+        let mut adapter = WalletAdapter::init().unwrap();
+        let events = adapter.subscribe();
+
+        let mock = mock_with_account("Mock Unregister");
+        let unregister = mock.register(adapter.window()).unwrap();
+        adapter.connect_by_name(mock.name()).await.unwrap();
+
+        unregister.unregister().unwrap();
+        assert!(adapter.get_wallet(mock.name()).is_err());
+
+        loop {
+            if let WalletEvent::Disconnected = events.recv().await.unwrap() {
+                break;
+            }
+        }
+        assert_eq!(
+            events.recv().await,
+            Ok(WalletEvent::WalletUnregistered(mock.name().to_string()))
+        );
+        assert!(!adapter.is_connected().await);
+
+        unregister.unregister().unwrap();
+        assert!(events.is_empty());
+    }
+
+    #[wasm_bindgen_test]
+    fn registers_on_app_ready_event() {
+        let mock = mock_with_account("Mock App Ready");
+        mock.register(&web_sys::window().unwrap()).unwrap();
+
+        let adapter = WalletAdapter::init().unwrap();
+
+        assert!(adapter.get_wallet("Mock App Ready").is_ok());
+    }
+
+    #[wasm_bindgen_test]
+    async fn unregisters_updated_wallet() {
+        let mut adapter = WalletAdapter::init().unwrap();
+        let events = adapter.subscribe();
+
+        let mock = mock_with_account("Mock Unregister Updated");
+        let unregister = mock.register(adapter.window()).unwrap();
+        adapter.connect_by_name(mock.name()).await.unwrap();
+
+        mock.emit_chains_changed(&["solana:devnet"]).unwrap();
+        loop {
+            if let WalletEvent::ChainsChanged(_) = events.recv().await.unwrap() {
+                break;
+            }
+        }
+
+        unregister.unregister().unwrap();
+        assert!(adapter.get_wallet(mock.name()).is_err());
+        loop {
+            if let WalletEvent::WalletUnregistered(name) = events.recv().await.unwrap() {
+                assert_eq!(name, mock.name());
+                break;
+            }
+        }
+        assert!(!adapter.is_connected().await);
+    }
+}
@@ -20,18 +20,47 @@ pub(crate) use utils::*;
 mod events;
 pub use events::*;
 
+mod event_bus;
+pub use event_bus::*;
+
 mod wallet_ser_der;
 pub use wallet_ser_der::*;
 
 mod storage;
 pub use storage::*;
 
+mod auto_connect;
+pub use auto_connect::*;
+
+mod catalog;
+pub use catalog::*;
+
+mod requests;
+pub use requests::*;
+
+#[cfg(feature = "mock")]
+mod mock;
+#[cfg(feature = "mock")]
+pub use mock::*;
+
+#[cfg(feature = "provider")]
+mod provider;
+#[cfg(feature = "provider")]
+pub use provider::*;
+
 // Re-export common crate
 pub use wallet_adapter_common::chains::*;
 pub use wallet_adapter_common::clusters::*;
 pub use wallet_adapter_common::feature_support::*;
 pub use wallet_adapter_common::standardized_events::*;
+pub use wallet_adapter_common::transaction;
+pub use wallet_adapter_common::TransactionVersion;
 pub use wallet_adapter_common::WalletCommonUtils as Utils;
+pub use wallet_adapter_common::{
+    KnownWallet, PreReleaseIdentifier, SemverComparator, SemverOp, SemverRequirement,
+    WalletCatalog, WalletListOptions, WalletReadiness, DEEPLINK_REF_PLACEHOLDER,
+    DEEPLINK_URL_PLACEHOLDER, WALLET_STANDARD_VERSION,
+};
 
 // Re-export of crates
 pub use async_channel;
@@ -43,5 +72,7 @@ pub use humantime;
 pub use rand_chacha;
 pub use rand_core;
 pub use thiserror;
+#[cfg(feature = "provider")]
+pub use wallet_standard_base;
 pub use wasm_bindgen_futures;
 pub use web_sys;
@@ -0,0 +1,116 @@
+use ed25519_dalek::{Signer, SigningKey};
+use wallet_adapter_common::{clusters::Cluster, WalletCommonUtils};
+use web_sys::{
+    js_sys::{Array, Uint8Array},
+    wasm_bindgen::JsValue,
+};
+
+use crate::{MockFeature, Reflection, WalletResult};
+
+/// An account of a [crate::MockWallet] backed by an Ed25519 keypair
+/// so that signatures returned by the mock wallet can be verified by the adapter
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MockAccount {
+    signing_key: SigningKey,
+    chains: Vec<String>,
+    features: Vec<String>,
+    label: Option<String>,
+    icon: Option<String>,
+}
+
+impl MockAccount {
+    /// Create an account from the 32 byte secret of an Ed25519 keypair.
+    /// The account supports all Solana clusters and all the features in [MockFeature::ALL]
+    pub fn new(secret_key: [u8; 32]) -> Self {
+        Self {
+            signing_key: SigningKey::from_bytes(&secret_key),
+            chains: [
+                Cluster::MainNet,
+                Cluster::DevNet,
+                Cluster::TestNet,
+                Cluster::LocalNet,
+            ]
+            .iter()
+            .map(|cluster| cluster.chain().to_string())
+            .collect(),
+            features: MockFeature::ALL
+                .iter()
+                .map(|feature| feature.identifier().to_string())
+                .collect(),
+            label: Option::None,
+            icon: Option::None,
+        }
+    }
+
+    /// Create an account from a randomly generated keypair
+    pub fn random() -> Self {
+        Self::new(WalletCommonUtils::rand_32bytes())
+    }
+
+    /// Replace the chains supported by the account
+    pub fn set_chains(mut self, chains: &[&str]) -> Self {
+        self.chains = chains.iter().map(|chain| chain.to_string()).collect();
+
+        self
+    }
+
+    /// Replace the features supported by the account
+    pub fn set_features(mut self, features: &[&str]) -> Self {
+        self.features = features.iter().map(|feature| feature.to_string()).collect();
+
+        self
+    }
+
+    /// Set the label of the account
+    pub fn set_label(mut self, label: &str) -> Self {
+        self.label.replace(label.to_string());
+
+        self
+    }
+
+    /// Set the icon of the account
+    pub fn set_icon(mut self, icon: &str) -> Self {
+        self.icon.replace(icon.to_string());
+
+        self
+    }
+
+    /// The Ed25519 public key of the account
+    pub fn public_key(&self) -> [u8; 32] {
+        self.signing_key.verifying_key().to_bytes()
+    }
+
+    /// The Base58 address of the account
+    pub fn address(&self) -> String {
+        WalletCommonUtils::address(self.signing_key.verifying_key())
+    }
+
+    /// Sign a message using the secret key of the account
+    pub fn sign(&self, message: &[u8]) -> [u8; 64] {
+        self.signing_key.sign(message).to_bytes()
+    }
+
+    /// Converts the account to the object a browser wallet exposes as a `WalletAccount`
+    pub fn to_jsvalue(&self) -> WalletResult<JsValue> {
+        let mut account = Reflection::new_object();
+        account.set_object_str("address", &self.address())?;
+        account.set_object(
+            &"publicKey".into(),
+            &Uint8Array::from(self.public_key().as_slice()).into(),
+        )?;
+        account.set_object(&"chains".into(), &Self::to_array(&self.chains))?;
+        account.set_object(&"features".into(), &Self::to_array(&self.features))?;
+        account.set_object_string_optional("label", self.label.as_ref())?;
+        account.set_object_string_optional("icon", self.icon.as_ref())?;
+
+        Ok(account.take())
+    }
+
+    pub(crate) fn to_array(values: &[String]) -> JsValue {
+        values
+            .iter()
+            .map(|value| JsValue::from_str(value))
+            .collect::<Array>()
+            .into()
+    }
+}
@@ -0,0 +1,12 @@
+mod outcome;
+pub use outcome::*;
+
+mod account;
+pub use account::*;
+
+mod wallet;
+pub use wallet::*;
+
+#[cfg(test)]
+#[cfg(target_arch = "wasm32")]
+pub(crate) mod test_utils;
@@ -0,0 +1,131 @@
+use wallet_adapter_common::standardized_events::{
+    SOLANA_SIGN_AND_SEND_ALL_TRANSACTIONS_IDENTIFIER, SOLANA_SIGN_AND_SEND_TRANSACTION_IDENTIFIER,
+    SOLANA_SIGN_IN_IDENTIFIER, SOLANA_SIGN_MESSAGE_IDENTIFIER, SOLANA_SIGN_TRANSACTION_IDENTIFIER,
+    STANDARD_CONNECT_IDENTIFIER, STANDARD_DISCONNECT_IDENTIFIER, STANDARD_EVENTS_IDENTIFIER,
+};
+use web_sys::{js_sys, wasm_bindgen::JsValue};
+
+/// The features of the wallet standard that a [crate::MockWallet] can expose
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+pub enum MockFeature {
+    /// `standard:connect`
+    Connect,
+    /// `standard:disconnect`
+    Disconnect,
+    /// `standard:events`
+    Events,
+    /// `solana:signIn`
+    SignIn,
+    /// `solana:signMessage`
+    SignMessage,
+    /// `solana:signTransaction`
+    SignTransaction,
+    /// `solana:signAndSendTransaction`
+    SignAndSendTransaction,
+    /// `solana:signAndSendAllTransactions`
+    SignAndSendAllTransactions,
+}
+
+impl MockFeature {
+    /// All the features a [crate::MockWallet] exposes by default
+    pub const ALL: [MockFeature; 8] = [
+        Self::Connect,
+        Self::Disconnect,
+        Self::Events,
+        Self::SignIn,
+        Self::SignMessage,
+        Self::SignTransaction,
+        Self::SignAndSendTransaction,
+        Self::SignAndSendAllTransactions,
+    ];
+
+    /// The namespaced identifier of the feature, example `standard:connect`
+    pub fn identifier(&self) -> &'static str {
+        match self {
+            Self::Connect => STANDARD_CONNECT_IDENTIFIER,
+            Self::Disconnect => STANDARD_DISCONNECT_IDENTIFIER,
+            Self::Events => STANDARD_EVENTS_IDENTIFIER,
+            Self::SignIn => SOLANA_SIGN_IN_IDENTIFIER,
+            Self::SignMessage => SOLANA_SIGN_MESSAGE_IDENTIFIER,
+            Self::SignTransaction => SOLANA_SIGN_TRANSACTION_IDENTIFIER,
+            Self::SignAndSendTransaction => SOLANA_SIGN_AND_SEND_TRANSACTION_IDENTIFIER,
+            Self::SignAndSendAllTransactions => SOLANA_SIGN_AND_SEND_ALL_TRANSACTIONS_IDENTIFIER,
+        }
+    }
+
+    /// The name of the method within the feature object, example `connect`
+    pub fn method(&self) -> &'static str {
+        match self {
+            Self::Connect => "connect",
+            Self::Disconnect => "disconnect",
+            Self::Events => "on",
+            Self::SignIn => "signIn",
+            Self::SignMessage => "signMessage",
+            Self::SignTransaction => "signTransaction",
+            Self::SignAndSendTransaction => "signAndSendTransaction",
+            Self::SignAndSendAllTransactions => "signAndSendAllTransactions",
+        }
+    }
+}
+
+/// The scripted outcome of a request made to a [crate::MockWallet]
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum MockOutcome {
+    /// Resolve the request the same way an honest browser wallet would,
+    /// by signing with the keys of the [crate::MockAccount]s
+    #[default]
+    Approve,
+    /// Reject the promise with a JavaScript `Error`
+    Reject {
+        /// The `name` of the JavaScript error
+        name: String,
+        /// The `message` of the JavaScript error
+        message: String,
+    },
+    /// Resolve the promise with this exact value. Useful to test how
+    /// malformed responses from a wallet are handled
+    Resolve(JsValue),
+    /// Never settle the promise, the same way some wallets do
+    /// when the user closes the wallet popup
+    Pending,
+}
+
+impl MockOutcome {
+    /// Reject the request the same way a wallet does when the user
+    /// declines the request in the wallet popup
+    pub fn user_rejected() -> Self {
+        Self::reject("WalletError", "User rejected the request.")
+    }
+
+    /// Reject the request with a JavaScript `Error` with a custom `name` and `message`
+    pub fn reject(name: &str, message: &str) -> Self {
+        Self::Reject {
+            name: name.to_string(),
+            message: message.to_string(),
+        }
+    }
+
+    /// Resolve the request with a custom value
+    pub fn resolve(value: impl Into<JsValue>) -> Self {
+        Self::Resolve(value.into())
+    }
+
+    pub(crate) fn js_error(name: &str, message: &str) -> JsValue {
+        let error = js_sys::Error::new(message);
+        error.set_name(name);
+
+        error.into()
+    }
+}
+
+/// A record of a request made to a [crate::MockWallet]
+#[derive(Debug, Clone, PartialEq)]
+pub struct MockCall {
+    /// The feature that was called
+    pub feature: MockFeature,
+    /// The first input the feature was called with
+    pub input: JsValue,
+    /// All the inputs the feature was called with. Features like
+    /// `solana:signTransaction` accept several inputs in one call
+    pub inputs: Vec<JsValue>,
+}
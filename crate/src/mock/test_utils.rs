@@ -0,0 +1,27 @@
+use wasm_bindgen_test::*;
+
+use crate::{MockAccount, MockWallet, WalletAdapter};
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+pub(crate) fn registered(mock: MockWallet) -> (MockWallet, WalletAdapter) {
+    let adapter = WalletAdapter::init().unwrap();
+    mock.register(adapter.window()).unwrap();
+
+    (mock, adapter)
+}
+
+pub(crate) fn mock_with_account(name: &str) -> MockWallet {
+    MockWallet::new(name).add_account(MockAccount::new([7u8; 32]).set_label("Mock Account"))
+}
+
+pub(crate) async fn sleep(millis: i32) {
+    let promise = web_sys::js_sys::Promise::new(&mut |resolve, _| {
+        web_sys::window()
+            .unwrap()
+            .set_timeout_with_callback_and_timeout_and_arguments_0(&resolve, millis)
+            .unwrap();
+    });
+
+    wasm_bindgen_futures::JsFuture::from(promise).await.unwrap();
+}
@@ -0,0 +1,548 @@
+use std::{
+    cell::RefCell,
+    collections::{HashMap, VecDeque},
+    rc::Rc,
+};
+
+use wallet_adapter_common::{clusters::Cluster, transaction::Transaction, WALLET_STANDARD_VERSION};
+use web_sys::{
+    js_sys::{self, Array, Function, Uint8Array},
+    wasm_bindgen::{prelude::Closure, JsValue},
+    Window,
+};
+
+use crate::{
+    InitEvents, InnerUtils, MockAccount, MockCall, MockFeature, MockOutcome, Reflection,
+    SigninInput, WalletError, WalletResult, WalletUnregister,
+};
+
+/// A scriptable wallet implementing the wallet standard.
+/// It builds the same JavaScript object a browser extension wallet registers
+/// so that the [crate::WalletAdapter] can be tested without a browser extension.
+///
+/// Every feature resolves with [MockOutcome::Approve] unless a different
+/// outcome is scripted using [MockWallet::set_outcome] or [MockWallet::queue_outcome].
+#[derive(Debug, Clone)]
+pub struct MockWallet {
+    name: String,
+    version: String,
+    icon: Option<String>,
+    chains: Vec<String>,
+    accounts: Vec<MockAccount>,
+    features: Vec<MockFeature>,
+    feature_version: String,
+    supported_transaction_versions: Vec<JsValue>,
+    custom_features: Vec<(String, JsValue)>,
+    state: Rc<RefCell<MockState>>,
+}
+
+#[derive(Debug, Default)]
+struct MockState {
+    outcomes: HashMap<MockFeature, MockOutcome>,
+    queued: HashMap<MockFeature, VecDeque<MockOutcome>>,
+    listeners: Vec<(u32, Function)>,
+    listener_ids: u32,
+    calls: Vec<MockCall>,
+}
+
+impl MockWallet {
+    /// Create a mock wallet with a `name` that supports all Solana clusters,
+    /// all the features in [MockFeature::ALL] and has no accounts
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            version: WALLET_STANDARD_VERSION.to_string(),
+            icon: Option::None,
+            chains: [
+                Cluster::MainNet,
+                Cluster::DevNet,
+                Cluster::TestNet,
+                Cluster::LocalNet,
+            ]
+            .iter()
+            .map(|cluster| cluster.chain().to_string())
+            .collect(),
+            accounts: Vec::default(),
+            features: MockFeature::ALL.to_vec(),
+            feature_version: WALLET_STANDARD_VERSION.to_string(),
+            supported_transaction_versions: vec![JsValue::from_str("legacy"), JsValue::from(0)],
+            custom_features: Vec::default(),
+            state: Rc::new(RefCell::new(MockState::default())),
+        }
+    }
+
+    /// Set the version of the wallet standard the wallet implements.
+    /// This is not validated so that invalid versions can be tested
+    pub fn set_version(mut self, version: &str) -> Self {
+        self.version = version.to_string();
+
+        self
+    }
+
+    /// Set the version of every feature object exposed by the wallet
+    pub fn set_feature_version(mut self, version: &str) -> Self {
+        self.feature_version = version.to_string();
+
+        self
+    }
+
+    /// Set the icon of the wallet
+    pub fn set_icon(mut self, icon: &str) -> Self {
+        self.icon.replace(icon.to_string());
+
+        self
+    }
+
+    /// Replace the chains advertised by the wallet
+    pub fn set_chains(mut self, chains: &[&str]) -> Self {
+        self.chains = chains.iter().map(|chain| chain.to_string()).collect();
+
+        self
+    }
+
+    /// Add an account to the wallet
+    pub fn add_account(mut self, account: MockAccount) -> Self {
+        self.accounts.push(account);
+
+        self
+    }
+
+    /// Replace the features exposed by the wallet
+    pub fn set_features(mut self, features: &[MockFeature]) -> Self {
+        self.features = features.to_vec();
+
+        self
+    }
+
+    /// Remove a feature exposed by the wallet
+    pub fn remove_feature(mut self, feature: MockFeature) -> Self {
+        self.features.retain(|current| current != &feature);
+
+        self
+    }
+
+    /// Add a feature object that is exposed as is, example a vendor
+    /// extension like `mock:ping` or a feature unknown to the adapter
+    pub fn add_custom_feature(mut self, identifier: &str, feature: JsValue) -> Self {
+        self.custom_features.push((identifier.to_string(), feature));
+
+        self
+    }
+
+    /// Set the `supportedTransactionVersions` of `solana:signTransaction`
+    /// and `solana:signAndSendTransaction`. Values that parse to a number
+    /// are exposed as numbers, example `["legacy", "0"]`
+    pub fn set_supported_transaction_versions(mut self, versions: &[&str]) -> Self {
+        self.supported_transaction_versions = versions
+            .iter()
+            .map(|version| match version.parse::<u8>() {
+                Ok(number) => JsValue::from(number),
+                Err(_) => JsValue::from_str(version),
+            })
+            .collect();
+
+        self
+    }
+
+    /// Script the outcome of every request made to a `feature`
+    pub fn set_outcome(&self, feature: MockFeature, outcome: MockOutcome) -> &Self {
+        self.state.borrow_mut().outcomes.insert(feature, outcome);
+
+        self
+    }
+
+    /// Script the outcome of the next request made to a `feature`.
+    /// Queued outcomes take precedence over outcomes set by [MockWallet::set_outcome]
+    pub fn queue_outcome(&self, feature: MockFeature, outcome: MockOutcome) -> &Self {
+        self.state
+            .borrow_mut()
+            .queued
+            .entry(feature)
+            .or_default()
+            .push_back(outcome);
+
+        self
+    }
+
+    /// All the requests made to the wallet in the order they were made
+    pub fn calls(&self) -> Vec<MockCall> {
+        self.state.borrow().calls.clone()
+    }
+
+    /// The requests made to a certain `feature` in the order they were made
+    pub fn calls_for(&self, feature: MockFeature) -> Vec<MockCall> {
+        self.state
+            .borrow()
+            .calls
+            .iter()
+            .filter(|call| call.feature == feature)
+            .cloned()
+            .collect()
+    }
+
+    /// The number of listeners subscribed to the `change` event using `standard:events`
+    pub fn listener_count(&self) -> usize {
+        self.state.borrow().listeners.len()
+    }
+
+    /// The name of the wallet
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The accounts of the wallet
+    pub fn accounts(&self) -> &[MockAccount] {
+        &self.accounts
+    }
+
+    /// Emit a `change` event to all `standard:events` listeners
+    pub fn emit_change(&self, properties: &JsValue) -> WalletResult<()> {
+        let listeners = self
+            .state
+            .borrow()
+            .listeners
+            .iter()
+            .map(|(_, listener)| listener.clone())
+            .collect::<Vec<Function>>();
+
+        listeners.iter().try_for_each(|listener| {
+            listener.call1(&JsValue::null(), properties)?;
+
+            Ok::<(), WalletError>(())
+        })
+    }
+
+    /// Emit a `change` event with the `accounts` property
+    pub fn emit_accounts_changed(&self, accounts: &[MockAccount]) -> WalletResult<()> {
+        let mut properties = Reflection::new_object();
+        properties.set_object(&"accounts".into(), &Self::accounts_array(accounts)?)?;
+
+        self.emit_change(properties.get_inner())
+    }
+
+    /// Emit a `change` event with the `chains` property
+    pub fn emit_chains_changed(&self, chains: &[&str]) -> WalletResult<()> {
+        let chains = chains
+            .iter()
+            .map(|chain| chain.to_string())
+            .collect::<Vec<String>>();
+
+        let mut properties = Reflection::new_object();
+        properties.set_object(&"chains".into(), &MockAccount::to_array(&chains))?;
+
+        self.emit_change(properties.get_inner())
+    }
+
+    /// Emit a `change` event with the `features` property exposing only `features`
+    /// and the features added by [MockWallet::add_custom_feature]
+    pub fn emit_features_changed(&self, features: &[MockFeature]) -> WalletResult<()> {
+        let mut properties = Reflection::new_object();
+        properties.set_object(&"features".into(), &self.features_object(features)?)?;
+
+        self.emit_change(properties.get_inner())
+    }
+
+    /// Register the wallet by dispatching the `wallet-standard:register-wallet` event
+    /// and by listening for the `wallet-standard:app-ready` event in case the
+    /// [crate::WalletAdapter] is initialized after the wallet is registered.
+    /// Use the returned [WalletUnregister] to unregister the wallet
+    pub fn register(&self, window: &Window) -> WalletResult<WalletUnregister> {
+        InitEvents::new(window).register_wallet(&self.to_jsvalue()?)
+    }
+
+    /// Build the JavaScript object that is passed to the `register` function of the wallet standard
+    pub fn to_jsvalue(&self) -> WalletResult<JsValue> {
+        let mut wallet = Reflection::new_object();
+        wallet.set_object_str("name", &self.name)?;
+        wallet.set_object_str("version", &self.version)?;
+        wallet.set_object_string_optional("icon", self.icon.as_ref())?;
+        wallet.set_object(&"chains".into(), &MockAccount::to_array(&self.chains))?;
+        wallet.set_object(&"accounts".into(), &Self::accounts_array(&self.accounts)?)?;
+
+        wallet.set_object(&"features".into(), &self.features_object(&self.features)?)?;
+
+        Ok(wallet.take())
+    }
+
+    fn features_object(&self, features: &[MockFeature]) -> WalletResult<JsValue> {
+        let mut features_object = Reflection::new_object();
+        features.iter().try_for_each(|feature| {
+            features_object.set_object(
+                &feature.identifier().into(),
+                &self.feature_object(*feature)?,
+            )?;
+
+            Ok::<(), WalletError>(())
+        })?;
+        self.custom_features
+            .iter()
+            .try_for_each(|(identifier, feature)| {
+                features_object.set_object(&identifier.into(), feature)?;
+
+                Ok::<(), WalletError>(())
+            })?;
+
+        Ok(features_object.take())
+    }
+
+    fn accounts_array(accounts: &[MockAccount]) -> WalletResult<JsValue> {
+        Ok(accounts
+            .iter()
+            .map(|account| account.to_jsvalue())
+            .collect::<WalletResult<Array>>()?
+            .into())
+    }
+
+    fn feature_object(&self, feature: MockFeature) -> WalletResult<JsValue> {
+        let mut feature_object = Reflection::new_object();
+        feature_object.set_object_str("version", &self.feature_version)?;
+
+        if matches!(
+            feature,
+            MockFeature::SignTransaction
+                | MockFeature::SignAndSendTransaction
+                | MockFeature::SignAndSendAllTransactions
+        ) {
+            feature_object.set_object(
+                &"supportedTransactionVersions".into(),
+                &self
+                    .supported_transaction_versions
+                    .iter()
+                    .collect::<Array>(),
+            )?;
+        }
+
+        let callback = if feature == MockFeature::Events {
+            self.events_callback()
+        } else {
+            self.request_callback(feature)?
+        };
+
+        feature_object.set_object(&feature.method().into(), &callback)?;
+
+        Ok(feature_object.take())
+    }
+
+    fn events_callback(&self) -> JsValue {
+        let state = self.state.clone();
+
+        Closure::wrap(Box::new(move |event: JsValue, listener: JsValue| {
+            state.borrow_mut().calls.push(MockCall {
+                feature: MockFeature::Events,
+                input: event.clone(),
+                inputs: vec![event.clone(), listener.clone()],
+            });
+
+            let listener = match Reflection::new(listener).and_then(|value| value.into_function()) {
+                Ok(listener) => listener,
+                Err(_) => return JsValue::undefined(),
+            };
+
+            if event.as_string().as_deref() != Some("change") {
+                return Closure::wrap(Box::new(|| {}) as Box<dyn Fn()>).into_js_value();
+            }
+
+            // `on` is not async so only a [MockOutcome::Resolve] outcome is used,
+            // its value is returned instead of the `unsubscribe` function
+            if let Some(MockOutcome::Resolve(value)) =
+                state.borrow().outcomes.get(&MockFeature::Events)
+            {
+                return value.clone();
+            }
+
+            let id = {
+                let mut state = state.borrow_mut();
+                state.listener_ids += 1;
+                let id = state.listener_ids;
+                state.listeners.push((id, listener));
+
+                id
+            };
+
+            let state = state.clone();
+            Closure::wrap(Box::new(move || {
+                state
+                    .borrow_mut()
+                    .listeners
+                    .retain(|(listener_id, _)| listener_id != &id);
+            }) as Box<dyn Fn()>)
+            .into_js_value()
+        }) as Box<dyn Fn(JsValue, JsValue) -> JsValue>)
+        .into_js_value()
+    }
+
+    fn request_callback(&self, feature: MockFeature) -> WalletResult<JsValue> {
+        let mock = self.clone();
+
+        InnerUtils::variadic_function(move |inputs: Array| {
+            mock.state.borrow_mut().calls.push(MockCall {
+                feature,
+                input: inputs.get(0),
+                inputs: inputs.to_vec(),
+            });
+
+            let outcome = {
+                let mut state = mock.state.borrow_mut();
+
+                match state
+                    .queued
+                    .get_mut(&feature)
+                    .and_then(|queue| queue.pop_front())
+                {
+                    Some(outcome) => outcome,
+                    None => state.outcomes.get(&feature).cloned().unwrap_or_default(),
+                }
+            };
+
+            let promise = match outcome {
+                MockOutcome::Approve => match mock.approve(feature, &inputs) {
+                    Ok(value) => js_sys::Promise::resolve(&value),
+                    Err(error) => js_sys::Promise::reject(&MockOutcome::js_error(
+                        "MockWalletError",
+                        &error.to_string(),
+                    )),
+                },
+                MockOutcome::Reject { name, message } => {
+                    js_sys::Promise::reject(&MockOutcome::js_error(&name, &message))
+                }
+                MockOutcome::Resolve(value) => js_sys::Promise::resolve(&value),
+                MockOutcome::Pending => js_sys::Promise::new(&mut |_, _| {}),
+            };
+
+            JsValue::from(promise)
+        })
+    }
+
+    fn approve(&self, feature: MockFeature, inputs: &Array) -> WalletResult<JsValue> {
+        match feature {
+            MockFeature::Connect => {
+                let mut output = Reflection::new_object();
+                output.set_object(&"accounts".into(), &Self::accounts_array(&self.accounts)?)?;
+
+                Ok(output.take())
+            }
+            MockFeature::Disconnect | MockFeature::Events => Ok(JsValue::undefined()),
+            MockFeature::SignMessage => Self::map_inputs(inputs, |input| {
+                let account = self.find_account(&input)?;
+                let message = input.reflect_bytes("message")?;
+
+                let mut output = Reflection::new_object();
+                output.set_object(
+                    &"signedMessage".into(),
+                    &Uint8Array::from(message.as_slice()),
+                )?;
+                output.set_object(
+                    &"signature".into(),
+                    &Uint8Array::from(account.sign(&message).as_slice()),
+                )?;
+
+                Ok(output.take())
+            }),
+            MockFeature::SignTransaction => Self::map_inputs(inputs, |input| {
+                let account = self.find_account(&input)?;
+                let transaction_bytes = input.reflect_bytes("transaction")?;
+
+                let mut transaction = Transaction::decode(&transaction_bytes)?;
+                let signer_index = transaction
+                    .message
+                    .signer_keys()
+                    .iter()
+                    .position(|signer| *signer == account.public_key())
+                    .ok_or(WalletError::AccountNotTransactionSigner(account.address()))?;
+                let signature = account.sign(Transaction::message_bytes(&transaction_bytes)?);
+                *transaction
+                    .signatures
+                    .get_mut(signer_index)
+                    .ok_or(WalletError::InvalidTransactionBytes)? = signature;
+
+                let mut output = Reflection::new_object();
+                output.set_object(
+                    &"signedTransaction".into(),
+                    &Uint8Array::from(transaction.encode()?.as_slice()),
+                )?;
+
+                Ok(output.take())
+            }),
+            MockFeature::SignAndSendTransaction => Self::map_inputs(inputs, |input| {
+                let account = self.find_account(&input)?;
+                let transaction = input.reflect_bytes("transaction")?;
+
+                let mut output = Reflection::new_object();
+                output.set_object(
+                    &"signature".into(),
+                    &Uint8Array::from(account.sign(&transaction).as_slice()),
+                )?;
+
+                Ok(output.take())
+            }),
+            MockFeature::SignAndSendAllTransactions => {
+                let transactions = Reflection::new(inputs.get(0))?.into_array()?;
+
+                Self::map_inputs(&transactions, |input| {
+                    let account = self.find_account(&input)?;
+                    let transaction = input.reflect_bytes("transaction")?;
+
+                    let mut value = Reflection::new_object();
+                    value.set_object(
+                        &"signature".into(),
+                        &Uint8Array::from(account.sign(&transaction).as_slice()),
+                    )?;
+
+                    let mut output = Reflection::new_object();
+                    output.set_object_str("status", "fulfilled")?;
+                    output.set_object(&"value".into(), value.get_inner())?;
+
+                    Ok(output.take())
+                })
+            }
+            MockFeature::SignIn => {
+                let input = Reflection::new(inputs.get(0))?;
+                let account = match input.string_optional("address")? {
+                    Some(address) => self
+                        .accounts
+                        .iter()
+                        .find(|account| account.address() == address)
+                        .ok_or(WalletError::AccountNotFound)?,
+                    None => self.accounts.first().ok_or(WalletError::AccountNotFound)?,
+                };
+                let message =
+                    SigninInput::from_object(&input)?.message("localhost", &account.address());
+
+                let mut output = Reflection::new_object();
+                output.set_object(&"account".into(), &account.to_jsvalue()?)?;
+                output.set_object(
+                    &"signedMessage".into(),
+                    &Uint8Array::from(message.as_bytes()),
+                )?;
+                output.set_object(
+                    &"signature".into(),
+                    &Uint8Array::from(account.sign(message.as_bytes()).as_slice()),
+                )?;
+
+                Ok(Array::of1(output.get_inner()).into())
+            }
+        }
+    }
+
+    /// Approve each input of a variadic feature and resolve with the outputs in the same order
+    fn map_inputs(
+        inputs: &Array,
+        approve_input: impl Fn(Reflection) -> WalletResult<JsValue>,
+    ) -> WalletResult<JsValue> {
+        Ok(inputs
+            .iter()
+            .map(|input| approve_input(Reflection::new(input)?))
+            .collect::<WalletResult<Array>>()?
+            .into())
+    }
+
+    fn find_account(&self, input: &Reflection) -> WalletResult<&MockAccount> {
+        let account = Reflection::new(input.reflect_inner("account")?)?;
+        let public_key = account.byte32array("publicKey")?;
+
+        self.accounts
+            .iter()
+            .find(|account| account.public_key() == public_key)
+            .ok_or(WalletError::AccountNotFound)
+    }
+}
@@ -0,0 +1,9 @@
+mod wallet_provider;
+pub use wallet_provider::*;
+
+mod wallet;
+pub use wallet::*;
+
+#[cfg(test)]
+#[cfg(target_arch = "wasm32")]
+mod tests;
@@ -0,0 +1,288 @@
+use ed25519_dalek::{Signer, SigningKey};
+use wallet_adapter_common::transaction::{
+    CompiledInstruction, Message, MessageHeader, Transaction,
+};
+use wallet_standard_base::{
+    Byte32Array, Byte64Array, SemverVersion, SignAndSendTransactionOutput, SignTransactionOutput,
+    StandardFeatures, Wallet as StandardWallet, WalletAccount as StandardWalletAccount,
+    WalletStandardIcon,
+};
+use wasm_bindgen_test::*;
+
+use crate::{
+    ProviderSignInOutput, ProviderWallet, SendOptions, SigninInput, WalletAdapter, WalletProvider,
+    WalletResult,
+};
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+struct RustWallet {
+    account: RustAccount,
+}
+
+#[derive(Clone)]
+struct RustAccount {
+    signing_key: SigningKey,
+    address: String,
+    public_key: Byte32Array,
+}
+
+struct Signed(Vec<u8>);
+
+struct Sent(Byte64Array);
+
+impl RustWallet {
+    fn new() -> Self {
+        let signing_key = SigningKey::from_bytes(&[9u8; 32]);
+        let public_key = signing_key.verifying_key().to_bytes();
+
+        Self {
+            account: RustAccount {
+                signing_key,
+                address: bs58::encode(public_key).into_string(),
+                public_key,
+            },
+        }
+    }
+}
+
+impl StandardFeatures for RustWallet {
+    fn namespace(&self) -> &str {
+        "solana"
+    }
+
+    fn sign_in(&self) -> Option<&str> {
+        Some("solana:signIn")
+    }
+
+    fn sign_message(&self) -> &str {
+        "solana:signMessage"
+    }
+
+    fn sign_transaction(&self) -> &str {
+        "solana:signTransaction"
+    }
+
+    fn sign_and_send_transaction(&self) -> &str {
+        "solana:signAndSendTransaction"
+    }
+}
+
+impl StandardWallet for RustWallet {
+    fn label(&self) -> &str {
+        "Rust Wallet"
+    }
+
+    fn version(&self) -> SemverVersion {
+        SemverVersion::new().set_major(1)
+    }
+
+    fn icon(&self) -> Option<WalletStandardIcon> {
+        None
+    }
+}
+
+impl StandardFeatures for RustAccount {
+    fn namespace(&self) -> &str {
+        "solana"
+    }
+
+    fn sign_in(&self) -> Option<&str> {
+        Some("solana:signIn")
+    }
+
+    fn sign_message(&self) -> &str {
+        "solana:signMessage"
+    }
+
+    fn sign_transaction(&self) -> &str {
+        "solana:signTransaction"
+    }
+
+    fn sign_and_send_transaction(&self) -> &str {
+        "solana:signAndSendTransaction"
+    }
+}
+
+impl StandardWalletAccount for RustAccount {
+    fn address(&self) -> &str {
+        &self.address
+    }
+
+    fn public_key(&self) -> &Byte32Array {
+        &self.public_key
+    }
+
+    fn icon(&self) -> Option<WalletStandardIcon> {
+        None
+    }
+
+    fn label(&self) -> Option<&str> {
+        Some("Rust Account")
+    }
+}
+
+impl SignTransactionOutput for Signed {
+    fn signed_transaction(&self) -> &[u8] {
+        &self.0
+    }
+
+    fn verify_signed_transaction<OutputError: core::error::Error>(
+        &self,
+    ) -> Result<(), OutputError> {
+        Ok(())
+    }
+}
+
+impl SignAndSendTransactionOutput for Sent {
+    fn signature(&self) -> &Byte64Array {
+        &self.0
+    }
+}
+
+impl WalletProvider for RustWallet {
+    type Account = RustAccount;
+    type SignedTransaction = Signed;
+    type SentTransaction = Sent;
+
+    fn chains(&self) -> Vec<String> {
+        vec!["solana:devnet".to_string()]
+    }
+
+    fn accounts(&self) -> Vec<Self::Account> {
+        vec![self.account.clone()]
+    }
+
+    async fn handle_connect(&self, _silent: bool) -> WalletResult<Vec<Self::Account>> {
+        Ok(self.accounts())
+    }
+
+    async fn handle_disconnect(&self) -> WalletResult<()> {
+        Ok(())
+    }
+
+    async fn handle_sign_message(
+        &self,
+        account: &Self::Account,
+        message: &[u8],
+    ) -> WalletResult<Byte64Array> {
+        Ok(account.signing_key.sign(message).to_bytes())
+    }
+
+    async fn handle_sign_transaction(
+        &self,
+        account: &Self::Account,
+        transaction: &[u8],
+        _chain: Option<&str>,
+    ) -> WalletResult<Self::SignedTransaction> {
+        let mut signed = Transaction::decode(transaction)?;
+        signed.signatures[0] = account
+            .signing_key
+            .sign(Transaction::message_bytes(transaction)?)
+            .to_bytes();
+
+        Ok(Signed(signed.encode()?))
+    }
+
+    async fn handle_sign_and_send_transaction(
+        &self,
+        account: &Self::Account,
+        transaction: &[u8],
+        _chain: &str,
+        _options: SendOptions,
+    ) -> WalletResult<Self::SentTransaction> {
+        Ok(Sent(account.signing_key.sign(transaction).to_bytes()))
+    }
+
+    async fn handle_sign_in(
+        &self,
+        input: SigninInput,
+    ) -> WalletResult<ProviderSignInOutput<Self::Account>> {
+        let message = input.message("localhost", &self.account.address);
+
+        Ok(ProviderSignInOutput {
+            account: self.account.clone(),
+            signature: self.account.signing_key.sign(message.as_bytes()).to_bytes(),
+            signed_message: message.into_bytes(),
+        })
+    }
+}
+
+fn registered() -> (ProviderWallet<RustWallet>, WalletAdapter) {
+    let adapter = WalletAdapter::init().unwrap();
+    let wallet = ProviderWallet::new(RustWallet::new());
+    wallet.register(adapter.window()).unwrap();
+
+    (wallet, adapter)
+}
+
+#[wasm_bindgen_test]
+fn registers_rust_wallet() {
+    let (wallet, adapter) = registered();
+
+    let registered = adapter.get_wallet("Rust Wallet").unwrap();
+    assert_eq!(registered.version().stringify_version(), "1.0.0");
+    assert!(registered.devnet());
+    assert!(!registered.mainnet());
+    assert!(registered.solana_signin());
+    assert_eq!(
+        registered.accounts()[0].public_key(),
+        wallet.provider().account.public_key
+    );
+}
+
+#[wasm_bindgen_test]
+async fn handles_requests() {
+    let (wallet, mut adapter) = registered();
+    adapter.connect_by_name("Rust Wallet").await.unwrap();
+
+    let signed = adapter.sign_message(b"Hello Rust").await.unwrap();
+    assert_eq!(
+        signed.signature().to_bytes(),
+        wallet
+            .provider()
+            .account
+            .signing_key
+            .sign(b"Hello Rust")
+            .to_bytes()
+    );
+
+    let transaction = Transaction {
+        signatures: vec![[0u8; 64]],
+        message: Message {
+            header: MessageHeader {
+                num_required_signatures: 1,
+                num_readonly_signed_accounts: 0,
+                num_readonly_unsigned_accounts: 1,
+            },
+            account_keys: vec![wallet.provider().account.public_key, [0u8; 32]],
+            recent_blockhash: [3u8; 32],
+            instructions: vec![CompiledInstruction {
+                program_id_index: 1,
+                accounts: vec![0],
+                data: vec![3u8; 12],
+            }],
+            ..Default::default()
+        },
+    }
+    .encode()
+    .unwrap();
+    let signed = adapter.sign_transaction(&transaction, None).await.unwrap();
+    assert_eq!(
+        Transaction::message_bytes(&signed[0]),
+        Transaction::message_bytes(&transaction)
+    );
+
+    let mut signin_input = SigninInput::new();
+    signin_input
+        .set_custom_domain("localhost")
+        .set_statement("Login To Rust")
+        .set_nonce()
+        .set_address(&wallet.provider().account.address)
+        .unwrap();
+    let output = adapter
+        .sign_in(&signin_input, wallet.provider().account.public_key)
+        .await
+        .unwrap();
+    assert_eq!(output.address(), wallet.provider().account.address);
+}
@@ -0,0 +1,457 @@
+use std::{cell::RefCell, rc::Rc};
+
+use wallet_adapter_common::WALLET_STANDARD_VERSION;
+use wallet_standard_base::{
+    SignAndSendTransactionOutput, SignTransactionOutput, StandardFeatures,
+    Wallet as StandardWallet, WalletAccount as StandardWalletAccount,
+};
+use web_sys::{
+    js_sys::{self, Array, Function, Object, Uint8Array},
+    wasm_bindgen::{prelude::Closure, JsCast, JsValue},
+    Window,
+};
+
+use crate::{
+    InitEvents, InnerUtils, ProviderSignInOutput, Reflection, SendOptions, SigninInput,
+    WalletError, WalletProvider, WalletResult, WalletUnregister,
+};
+
+/// Exposes a [WalletProvider] as the JavaScript object a browser extension wallet
+/// registers with the wallet standard so that any app using the wallet standard,
+/// including the [crate::WalletAdapter], can discover and use it.
+pub struct ProviderWallet<P: WalletProvider> {
+    provider: Rc<P>,
+    listeners: Rc<RefCell<ProviderListeners>>,
+}
+
+#[derive(Debug, Default)]
+struct ProviderListeners {
+    listeners: Vec<(u32, Function)>,
+    ids: u32,
+}
+
+impl<P: WalletProvider> ProviderWallet<P> {
+    /// Instantiate [Self] from a [WalletProvider]
+    pub fn new(provider: P) -> Self {
+        Self {
+            provider: Rc::new(provider),
+            listeners: Rc::default(),
+        }
+    }
+
+    /// Get the [WalletProvider]
+    pub fn provider(&self) -> &P {
+        &self.provider
+    }
+
+    /// Register the wallet by dispatching the `wallet-standard:register-wallet` event
+    /// and by listening for the `wallet-standard:app-ready` event in case an app
+    /// is initialized after the wallet is registered.
+    /// Use the returned [WalletUnregister] to unregister the wallet
+    pub fn register(&self, window: &Window) -> WalletResult<WalletUnregister> {
+        InitEvents::new(window).register_wallet(&self.to_jsvalue()?)
+    }
+
+    /// Emit a `change` event with the current [WalletProvider::accounts]
+    /// to all the listeners subscribed using `standard:events`
+    pub fn emit_accounts_changed(&self) -> WalletResult<()> {
+        let mut properties = Reflection::new_object();
+        properties.set_object(
+            &"accounts".into(),
+            &Self::accounts_array(&self.provider, &self.provider.accounts())?,
+        )?;
+
+        self.emit_change(properties.get_inner())
+    }
+
+    fn emit_change(&self, properties: &JsValue) -> WalletResult<()> {
+        let listeners = self
+            .listeners
+            .borrow()
+            .listeners
+            .iter()
+            .map(|(_, listener)| listener.clone())
+            .collect::<Vec<Function>>();
+
+        listeners.iter().try_for_each(|listener| {
+            listener.call1(&JsValue::null(), properties)?;
+
+            Ok(())
+        })
+    }
+
+    /// Build the JavaScript object that is passed to the `register` function of the wallet standard
+    pub fn to_jsvalue(&self) -> WalletResult<JsValue> {
+        let provider = self.provider.as_ref();
+
+        let mut wallet = Reflection::new_object();
+        wallet.set_object_str("name", provider.label())?;
+        wallet.set_object_str("version", &StandardWallet::version(provider).to_string())?;
+        wallet.set_object_string_optional(
+            "icon",
+            StandardWallet::icon(provider)
+                .map(|icon| icon.base64().to_string())
+                .as_ref(),
+        )?;
+        wallet.set_object(&"chains".into(), &Self::string_array(&provider.chains()))?;
+        wallet.set_object(&"features".into(), &self.features_object()?)?;
+
+        // The accounts are read each time since the authorized accounts change over time
+        let accounts_provider = self.provider.clone();
+        let accounts_getter = Closure::wrap(Box::new(move || {
+            Self::accounts_array(&accounts_provider, &accounts_provider.accounts())
+                .unwrap_or(Array::new().into())
+        }) as Box<dyn Fn() -> JsValue>);
+
+        let mut descriptor = Reflection::new_object();
+        descriptor.set_object(&"get".into(), &accounts_getter.into_js_value())?;
+        descriptor.set_object(&"enumerable".into(), &JsValue::TRUE)?;
+
+        let wallet = wallet.take();
+        Object::define_property(
+            wallet.unchecked_ref::<Object>(),
+            &"accounts".into(),
+            descriptor.get_inner().unchecked_ref::<Object>(),
+        );
+
+        Ok(wallet)
+    }
+
+    fn features_object(&self) -> WalletResult<JsValue> {
+        let provider = self.provider.as_ref();
+        let versions = Some(provider.supported_transaction_versions());
+
+        let mut features = vec![
+            (
+                provider.connect(),
+                "connect",
+                self.request_callback(Self::connect)?,
+                None,
+            ),
+            (
+                provider.disconnect(),
+                "disconnect",
+                self.request_callback(Self::disconnect)?,
+                None,
+            ),
+            (provider.events(), "on", self.events_callback(), None),
+            (
+                provider.sign_message(),
+                "signMessage",
+                self.request_callback(Self::sign_message)?,
+                None,
+            ),
+            (
+                provider.sign_transaction(),
+                "signTransaction",
+                self.request_callback(Self::sign_transaction)?,
+                versions,
+            ),
+            (
+                provider.sign_and_send_transaction(),
+                "signAndSendTransaction",
+                self.request_callback(Self::sign_and_send_transaction)?,
+                versions,
+            ),
+        ];
+
+        if let Some(sign_in) = provider.sign_in() {
+            features.push((
+                sign_in,
+                "signIn",
+                self.request_callback(Self::sign_in)?,
+                None,
+            ));
+        }
+
+        let mut features_object = Reflection::new_object();
+        features
+            .into_iter()
+            .try_for_each(|(identifier, method, callback, versions)| {
+                let mut feature = Reflection::new_object();
+                feature.set_object_str("version", WALLET_STANDARD_VERSION)?;
+                feature.set_object(&method.into(), &callback)?;
+
+                if let Some(versions) = versions {
+                    feature.set_object(
+                        &"supportedTransactionVersions".into(),
+                        &Self::transaction_versions_array(versions),
+                    )?;
+                }
+
+                features_object.set_object(&identifier.into(), feature.get_inner())?;
+
+                Ok::<(), WalletError>(())
+            })?;
+
+        Ok(features_object.take())
+    }
+
+    fn events_callback(&self) -> JsValue {
+        let listeners = self.listeners.clone();
+
+        Closure::wrap(Box::new(move |event: JsValue, listener: JsValue| {
+            let listener = match Reflection::new(listener).and_then(|value| value.into_function()) {
+                Ok(listener) => listener,
+                Err(_) => return JsValue::undefined(),
+            };
+
+            if event.as_string().as_deref() != Some("change") {
+                return Closure::wrap(Box::new(|| {}) as Box<dyn Fn()>).into_js_value();
+            }
+
+            let id = {
+                let mut listeners = listeners.borrow_mut();
+                listeners.ids += 1;
+                let id = listeners.ids;
+                listeners.listeners.push((id, listener));
+
+                id
+            };
+
+            let listeners = listeners.clone();
+            Closure::wrap(Box::new(move || {
+                listeners
+                    .borrow_mut()
+                    .listeners
+                    .retain(|(listener_id, _)| listener_id != &id);
+            }) as Box<dyn Fn()>)
+            .into_js_value()
+        }) as Box<dyn Fn(JsValue, JsValue) -> JsValue>)
+        .into_js_value()
+    }
+
+    fn request_callback<F, Fut>(&self, handler: F) -> WalletResult<JsValue>
+    where
+        F: Fn(Rc<P>, Array) -> Fut + 'static,
+        Fut: std::future::Future<Output = WalletResult<JsValue>> + 'static,
+    {
+        let provider = self.provider.clone();
+
+        InnerUtils::variadic_function(move |inputs: Array| {
+            let outcome = handler(provider.clone(), inputs);
+
+            JsValue::from(wasm_bindgen_futures::future_to_promise(async move {
+                outcome.await.map_err(to_js_error)
+            }))
+        })
+    }
+
+    async fn connect(provider: Rc<P>, inputs: Array) -> WalletResult<JsValue> {
+        let silent = Reflection::new(inputs.get(0))
+            .and_then(|input| input.reflect_inner("silent"))
+            .ok()
+            .and_then(|silent| silent.as_bool())
+            .unwrap_or_default();
+
+        let accounts = provider.handle_connect(silent).await?;
+
+        let mut output = Reflection::new_object();
+        output.set_object(
+            &"accounts".into(),
+            &Self::accounts_array(&provider, &accounts)?,
+        )?;
+
+        Ok(output.take())
+    }
+
+    async fn disconnect(provider: Rc<P>, _inputs: Array) -> WalletResult<JsValue> {
+        provider.handle_disconnect().await?;
+
+        Ok(JsValue::undefined())
+    }
+
+    async fn sign_message(provider: Rc<P>, inputs: Array) -> WalletResult<JsValue> {
+        let outputs = Array::new();
+
+        for input in inputs.iter() {
+            let input = Reflection::new(input)?;
+            let account = Self::find_account(&provider, &input)?;
+            let message = input.reflect_bytes("message")?;
+
+            let signature = provider.handle_sign_message(&account, &message).await?;
+
+            let mut output = Reflection::new_object();
+            output.set_object(
+                &"signedMessage".into(),
+                &Uint8Array::from(message.as_slice()),
+            )?;
+            output.set_object(&"signature".into(), &Uint8Array::from(signature.as_slice()))?;
+
+            outputs.push(output.get_inner());
+        }
+
+        Ok(outputs.into())
+    }
+
+    async fn sign_transaction(provider: Rc<P>, inputs: Array) -> WalletResult<JsValue> {
+        let outputs = Array::new();
+
+        for input in inputs.iter() {
+            let input = Reflection::new(input)?;
+            let account = Self::find_account(&provider, &input)?;
+            let transaction = input.reflect_bytes("transaction")?;
+            let chain = input.string_optional("chain")?;
+
+            let signed = provider
+                .handle_sign_transaction(&account, &transaction, chain.as_deref())
+                .await?;
+
+            let mut output = Reflection::new_object();
+            output.set_object(
+                &"signedTransaction".into(),
+                &Uint8Array::from(signed.signed_transaction()),
+            )?;
+
+            outputs.push(output.get_inner());
+        }
+
+        Ok(outputs.into())
+    }
+
+    async fn sign_and_send_transaction(provider: Rc<P>, inputs: Array) -> WalletResult<JsValue> {
+        let outputs = Array::new();
+
+        for input in inputs.iter() {
+            let input = Reflection::new(input)?;
+            let account = Self::find_account(&provider, &input)?;
+            let transaction = input.reflect_bytes("transaction")?;
+            let chain = input.string("chain")?;
+            let options =
+                SendOptions::from_object(&input.reflect_inner("options").unwrap_or_default())?;
+
+            let sent = provider
+                .handle_sign_and_send_transaction(&account, &transaction, &chain, options)
+                .await?;
+
+            let mut output = Reflection::new_object();
+            output.set_object(
+                &"signature".into(),
+                &Uint8Array::from(sent.signature().as_slice()),
+            )?;
+
+            outputs.push(output.get_inner());
+        }
+
+        Ok(outputs.into())
+    }
+
+    async fn sign_in(provider: Rc<P>, inputs: Array) -> WalletResult<JsValue> {
+        let input = match Reflection::new(inputs.get(0)) {
+            Ok(input) => SigninInput::from_object(&input)?,
+            Err(_) => SigninInput::default(),
+        };
+
+        let ProviderSignInOutput {
+            account,
+            signed_message,
+            signature,
+        } = provider.handle_sign_in(input).await?;
+
+        let mut output = Reflection::new_object();
+        output.set_object(
+            &"account".into(),
+            &Self::account_object(&provider, &account)?,
+        )?;
+        output.set_object(
+            &"signedMessage".into(),
+            &Uint8Array::from(signed_message.as_slice()),
+        )?;
+        output.set_object(&"signature".into(), &Uint8Array::from(signature.as_slice()))?;
+
+        Ok(Array::of1(output.get_inner()).into())
+    }
+
+    fn find_account(provider: &P, input: &Reflection) -> WalletResult<P::Account> {
+        let account = Reflection::new(input.reflect_inner("account")?)?;
+        let public_key = account.byte32array("publicKey")?;
+
+        provider
+            .accounts()
+            .into_iter()
+            .find(|account| account.public_key() == &public_key)
+            .ok_or(WalletError::AccountNotFound)
+    }
+
+    fn accounts_array(provider: &P, accounts: &[P::Account]) -> WalletResult<JsValue> {
+        Ok(accounts
+            .iter()
+            .map(|account| Self::account_object(provider, account))
+            .collect::<WalletResult<Array>>()?
+            .into())
+    }
+
+    fn account_object(provider: &P, account: &P::Account) -> WalletResult<JsValue> {
+        let mut features = vec![
+            account.sign_message(),
+            account.sign_transaction(),
+            account.sign_and_send_transaction(),
+        ];
+        if let Some(sign_in) = account.sign_in() {
+            features.push(sign_in);
+        }
+
+        let mut object = Reflection::new_object();
+        object.set_object_str("address", account.address())?;
+        object.set_object(
+            &"publicKey".into(),
+            &Uint8Array::from(account.public_key().as_slice()),
+        )?;
+        object.set_object(&"chains".into(), &Self::string_array(&provider.chains()))?;
+        object.set_object(&"features".into(), &Self::string_array(&features))?;
+        object.set_object_string_optional(
+            "label",
+            account.label().map(|label| label.to_string()).as_ref(),
+        )?;
+        object.set_object_string_optional(
+            "icon",
+            StandardWalletAccount::icon(account)
+                .map(|icon| icon.base64().to_string())
+                .as_ref(),
+        )?;
+
+        Ok(object.take())
+    }
+
+    fn string_array<T: AsRef<str>>(values: &[T]) -> JsValue {
+        values
+            .iter()
+            .map(|value| JsValue::from_str(value.as_ref()))
+            .collect::<Array>()
+            .into()
+    }
+
+    fn transaction_versions_array(versions: &[&str]) -> JsValue {
+        versions
+            .iter()
+            .map(|version| match version.parse::<u8>() {
+                Ok(number) => JsValue::from(number),
+                Err(_) => JsValue::from_str(version),
+            })
+            .collect::<Array>()
+            .into()
+    }
+}
+
+impl<P: WalletProvider> Clone for ProviderWallet<P> {
+    fn clone(&self) -> Self {
+        Self {
+            provider: self.provider.clone(),
+            listeners: self.listeners.clone(),
+        }
+    }
+}
+
+/// Converts a [WalletError] into the JavaScript `Error` an app receives
+/// when a request is rejected
+fn to_js_error(error: WalletError) -> JsValue {
+    let (name, message) = match error {
+        WalletError::JsError { name, message, .. } => (name, message),
+        error => ("WalletError".to_string(), error.to_string()),
+    };
+
+    let js_error = js_sys::Error::new(&message);
+    js_error.set_name(&name);
+
+    js_error.into()
+}
@@ -0,0 +1,85 @@
+use std::future::Future;
+
+use wallet_standard_base::{
+    Byte64Array, SignAndSendTransactionOutput, SignTransactionOutput, Wallet as StandardWallet,
+    WalletAccount as StandardWalletAccount,
+};
+
+use crate::{SendOptions, SigninInput, WalletResult};
+
+/// A wallet implemented in Rust. The identity of the wallet and the identifiers of the
+/// features it supports are described by the traits of the [wallet_standard_base] crate
+/// while the requests made by an app are handled by the `handle_*` methods.
+///
+/// Register the wallet with the wallet standard using [crate::ProviderWallet]
+pub trait WalletProvider: StandardWallet + 'static {
+    /// An account of the wallet
+    type Account: StandardWalletAccount + Clone;
+    /// The output of `signTransaction`
+    type SignedTransaction: SignTransactionOutput;
+    /// The output of `signAndSendTransaction`
+    type SentTransaction: SignAndSendTransactionOutput;
+
+    /// The chains supported by the wallet, example `solana:mainnet`
+    fn chains(&self) -> Vec<String>;
+
+    /// The accounts the app is authorized to use
+    fn accounts(&self) -> Vec<Self::Account>;
+
+    /// The transaction versions supported by `signTransaction` and `signAndSendTransaction`.
+    /// Versions that parse to a number are exposed as numbers
+    fn supported_transaction_versions(&self) -> &[&str] {
+        &["legacy", "0"]
+    }
+
+    /// Handle a `connect` request. A `silent` request must not prompt the user
+    fn handle_connect(
+        &self,
+        silent: bool,
+    ) -> impl Future<Output = WalletResult<Vec<Self::Account>>>;
+
+    /// Handle a `disconnect` request
+    fn handle_disconnect(&self) -> impl Future<Output = WalletResult<()>>;
+
+    /// Handle a `signMessage` request and return the Ed25519 signature of the `message`
+    fn handle_sign_message(
+        &self,
+        account: &Self::Account,
+        message: &[u8],
+    ) -> impl Future<Output = WalletResult<Byte64Array>>;
+
+    /// Handle a `signTransaction` request
+    fn handle_sign_transaction(
+        &self,
+        account: &Self::Account,
+        transaction: &[u8],
+        chain: Option<&str>,
+    ) -> impl Future<Output = WalletResult<Self::SignedTransaction>>;
+
+    /// Handle a `signAndSendTransaction` request
+    fn handle_sign_and_send_transaction(
+        &self,
+        account: &Self::Account,
+        transaction: &[u8],
+        chain: &str,
+        options: SendOptions,
+    ) -> impl Future<Output = WalletResult<Self::SentTransaction>>;
+
+    /// Handle a `signIn` request. This is only called if [wallet_standard_base::StandardFeatures::sign_in]
+    /// returns an identifier. The message to sign can be built using [SigninInput::message]
+    fn handle_sign_in(
+        &self,
+        input: SigninInput,
+    ) -> impl Future<Output = WalletResult<ProviderSignInOutput<Self::Account>>>;
+}
+
+/// The output of [WalletProvider::handle_sign_in]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProviderSignInOutput<A> {
+    /// The account that signed in
+    pub account: A,
+    /// The Sign In With Solana message that was signed
+    pub signed_message: Vec<u8>,
+    /// The Ed25519 signature of the `signed_message`
+    pub signature: Byte64Array,
+}
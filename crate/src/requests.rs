@@ -0,0 +1,260 @@
+use core::{
+    future::{poll_fn, Future},
+    pin::pin,
+    task::Poll,
+    time::Duration,
+};
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+};
+
+use async_channel::{bounded, Receiver, Sender};
+use web_sys::{js_sys::Promise, Window};
+
+use crate::{WalletError, WalletResult};
+
+/// Abandons every wallet request of the [crate::WalletAdapter] that is pending when
+/// [Self::cancel] is called, example when the user navigates away.
+/// It is not possible to cancel a single request, see [crate::WalletAdapter::adapter_cancel_handle]
+#[derive(Debug, Clone)]
+pub struct AdapterCancelHandle {
+    sender: Sender<()>,
+    receiver: Receiver<()>,
+}
+
+impl AdapterCancelHandle {
+    fn new() -> Self {
+        let (sender, receiver) = bounded(1);
+
+        Self { sender, receiver }
+    }
+
+    /// Abandon all the pending requests of the adapter which then return [WalletError::RequestCancelled].
+    /// Requests made after cancelling are not affected
+    pub fn cancel(&self) {
+        self.sender.close();
+    }
+
+    /// Check whether [Self::cancel] was called
+    pub fn is_cancelled(&self) -> bool {
+        self.sender.is_closed()
+    }
+
+    async fn cancelled(&self) {
+        // Nothing is ever sent so this only returns once the channel is closed
+        let _ = self.receiver.recv().await;
+    }
+}
+
+/// The timeout and cancellation of the wallet requests made by the [crate::WalletAdapter].
+/// Clones share the timeout and the cancel handle
+#[derive(Debug, Clone)]
+pub(crate) struct WalletRequests {
+    window: Window,
+    timeout: Rc<Cell<Option<Duration>>>,
+    cancel_handle: Rc<RefCell<AdapterCancelHandle>>,
+}
+
+impl WalletRequests {
+    pub(crate) fn new(window: Window) -> Self {
+        Self {
+            window,
+            timeout: Rc::default(),
+            cancel_handle: Rc::new(RefCell::new(AdapterCancelHandle::new())),
+        }
+    }
+
+    pub(crate) fn set_timeout(&mut self, timeout: Option<Duration>) -> &mut Self {
+        self.timeout.set(timeout);
+
+        self
+    }
+
+    pub(crate) fn timeout(&self) -> Option<Duration> {
+        self.timeout.get()
+    }
+
+    /// The handle of the pending requests, a new handle is created once it is cancelled
+    pub(crate) fn cancel_handle(&self) -> AdapterCancelHandle {
+        let mut cancel_handle = self.cancel_handle.borrow_mut();
+
+        if cancel_handle.is_cancelled() {
+            *cancel_handle = AdapterCancelHandle::new();
+        }
+
+        cancel_handle.clone()
+    }
+
+    /// Wait for the `request` unless it times out or is cancelled
+    pub(crate) async fn run<T>(
+        &self,
+        request: impl Future<Output = WalletResult<T>>,
+    ) -> WalletResult<T> {
+        let cancel_handle = self.cancel_handle();
+
+        let cancelled = async {
+            cancel_handle.cancelled().await;
+
+            Err(WalletError::RequestCancelled)
+        };
+
+        let timed_out = async {
+            match self.timeout() {
+                Some(timeout) => {
+                    Self::sleep(&self.window, timeout).await?;
+
+                    Err(WalletError::Timeout)
+                }
+                None => core::future::pending().await,
+            }
+        };
+
+        Self::race(request, Self::race(cancelled, timed_out)).await
+    }
+
+    // Resolves with the output of the future that is ready first
+    async fn race<T>(first: impl Future<Output = T>, second: impl Future<Output = T>) -> T {
+        let mut first = pin!(first);
+        let mut second = pin!(second);
+
+        poll_fn(|cx| {
+            if let Poll::Ready(output) = first.as_mut().poll(cx) {
+                return Poll::Ready(output);
+            }
+
+            second.as_mut().poll(cx)
+        })
+        .await
+    }
+
+    async fn sleep(window: &Window, duration: Duration) -> WalletResult<()> {
+        let millis = i32::try_from(duration.as_millis()).unwrap_or(i32::MAX);
+
+        let mut scheduled = Ok(0);
+        let promise = Promise::new(&mut |resolve, _| {
+            scheduled =
+                window.set_timeout_with_callback_and_timeout_and_arguments_0(&resolve, millis);
+        });
+        let handle = scheduled?;
+        // Dropped along with the sleep once the request settles first
+        let _timer = TimerGuard(|| window.clear_timeout_with_handle(handle));
+
+        wasm_bindgen_futures::JsFuture::from(promise).await?;
+
+        Ok(())
+    }
+}
+
+// Clears the `setTimeout` timer when dropped
+struct TimerGuard<F: FnMut()>(F);
+
+impl<F: FnMut()> Drop for TimerGuard<F> {
+    fn drop(&mut self) {
+        (self.0)();
+    }
+}
+
+#[cfg(test)]
+mod requests_tests {
+    use core::cell::Cell;
+
+    use super::*;
+    use crate::block_on;
+
+    // Pending on the first poll like a wallet request waiting for the user
+    async fn settle_later<T>(output: T) -> T {
+        let mut polled = false;
+        poll_fn(|_| {
+            if core::mem::replace(&mut polled, true) {
+                Poll::Ready(())
+            } else {
+                Poll::Pending
+            }
+        })
+        .await;
+
+        output
+    }
+
+    #[test]
+    fn clears_timer_once_request_settles() {
+        let cleared = Cell::new(0);
+        let timer = async {
+            let _timer = TimerGuard(|| cleared.set(cleared.get() + 1));
+            core::future::pending::<WalletResult<u8>>().await
+        };
+
+        let outcome = block_on(WalletRequests::race(settle_later(Ok(1)), timer));
+        assert_eq!(outcome, Ok(1));
+        assert_eq!(cleared.get(), 1);
+    }
+
+    #[test]
+    fn abandons_request_once_timer_fires() {
+        let abandoned = Cell::new(false);
+        let request = async {
+            let _request = TimerGuard(|| abandoned.set(true));
+            core::future::pending::<WalletResult<u8>>().await
+        };
+
+        let outcome = block_on(WalletRequests::race(
+            request,
+            settle_later(Err(WalletError::Timeout)),
+        ));
+        assert_eq!(outcome, Err(WalletError::Timeout));
+        assert!(abandoned.get());
+    }
+
+    #[test]
+    fn cancel_handle_cancels_once() {
+        let cancel_handle = AdapterCancelHandle::new();
+        assert!(!cancel_handle.is_cancelled());
+
+        cancel_handle.cancel();
+        assert!(cancel_handle.is_cancelled());
+        block_on(cancel_handle.cancelled());
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "mock")]
+#[cfg(target_arch = "wasm32")]
+mod requests_wasm_tests {
+    use std::time::Duration;
+
+    use wasm_bindgen_test::*;
+
+    use crate::{
+        mock::test_utils::{mock_with_account, registered, sleep},
+        MockFeature, MockOutcome, WalletError,
+    };
+
+    #[wasm_bindgen_test]
+    async fn times_out_and_cancels_requests() {
+        let (mock, mut adapter) = registered(mock_with_account("Mock Timeout"));
+        adapter.set_request_timeout(Some(Duration::from_millis(50)));
+
+        mock.queue_outcome(MockFeature::Connect, MockOutcome::Pending);
+        assert_eq!(
+            adapter.connect_by_name(mock.name()).await,
+            Err(WalletError::Timeout)
+        );
+        adapter.connect_by_name(mock.name()).await.unwrap();
+
+        adapter.set_request_timeout(None);
+        mock.queue_outcome(MockFeature::SignMessage, MockOutcome::Pending);
+        let cancel_handle = adapter.adapter_cancel_handle();
+        wasm_bindgen_futures::spawn_local(async move {
+            sleep(50).await;
+            cancel_handle.cancel();
+        });
+        assert!(matches!(
+            adapter.sign_message(b"Pending").await,
+            Err(WalletError::RequestCancelled)
+        ));
+
+        assert!(!adapter.adapter_cancel_handle().is_cancelled());
+        assert!(adapter.sign_message(b"Approved").await.is_ok());
+    }
+}
@@ -1,9 +1,118 @@
-use std::{cell::RefCell, collections::HashMap, rc::Rc};
+use std::{cell::RefCell, rc::Rc};
 
-use crate::Wallet;
+use crate::{FeatureParser, FeatureParsers, Wallet};
 
-/// Convenience type for `HashMap<blake3::Hash, Wallet>;`
-pub type StorageSchema = HashMap<blake3::Hash, Wallet>;
+/// The registered wallets keyed by the [blake3::Hash] of the lowercase wallet name.
+/// Wallets are kept in the order they first registered.
+/// Each registration gets an id so that the `unregister` function of a registration
+/// only removes the wallet it registered, even after the wallet is updated.
+/// The registered [FeatureParsers] parse the features of the wallets when they register
+#[derive(Debug, Default, PartialEq, Eq, Clone)]
+pub struct StorageSchema {
+    wallets: Vec<(blake3::Hash, u64, Wallet)>,
+    registrations: u64,
+    parsers: FeatureParsers,
+}
+
+impl StorageSchema {
+    /// The key of a wallet name
+    pub fn key(wallet_name: &str) -> blake3::Hash {
+        blake3::hash(wallet_name.to_lowercase().as_bytes())
+    }
+
+    /// Insert a wallet with a new registration id returning the wallet it replaced.
+    /// A wallet that registers again keeps its position
+    pub fn insert(&mut self, wallet: Wallet) -> Option<Wallet> {
+        let key = Self::key(wallet.name());
+        self.registrations += 1;
+        let registration_id = self.registrations;
+
+        match self
+            .wallets
+            .iter_mut()
+            .find(|(existing, _, _)| *existing == key)
+        {
+            Some((_, existing_id, existing)) => {
+                *existing_id = registration_id;
+
+                Some(core::mem::replace(existing, wallet))
+            }
+            None => {
+                self.wallets.push((key, registration_id, wallet));
+
+                Option::None
+            }
+        }
+    }
+
+    /// Replace a wallet with an updated `wallet` of the same name keeping its registration id,
+    /// returning the wallet it replaced. A wallet that is not registered is not added
+    pub fn update(&mut self, wallet: Wallet) -> Option<Wallet> {
+        let key = Self::key(wallet.name());
+
+        self.wallets
+            .iter_mut()
+            .find(|(existing, _, _)| *existing == key)
+            .map(|(_, _, existing)| core::mem::replace(existing, wallet))
+    }
+
+    /// Get a wallet by name
+    pub fn get(&self, wallet_name: &str) -> Option<&Wallet> {
+        let key = Self::key(wallet_name);
+
+        self.wallets
+            .iter()
+            .find(|(existing, _, _)| *existing == key)
+            .map(|(_, _, wallet)| wallet)
+    }
+
+    /// Get the id of the latest registration of a wallet by name
+    pub fn registration_id(&self, wallet_name: &str) -> Option<u64> {
+        let key = Self::key(wallet_name);
+
+        self.wallets
+            .iter()
+            .find(|(existing, _, _)| *existing == key)
+            .map(|(_, registration_id, _)| *registration_id)
+    }
+
+    /// Remove a wallet by name
+    pub fn remove(&mut self, wallet_name: &str) -> Option<Wallet> {
+        let key = Self::key(wallet_name);
+
+        self.wallets
+            .iter()
+            .position(|(existing, _, _)| *existing == key)
+            .map(|position| self.wallets.remove(position).2)
+    }
+
+    /// Iterate over the wallets in the order they first registered
+    pub fn wallets(&self) -> impl Iterator<Item = &Wallet> {
+        self.wallets.iter().map(|(_, _, wallet)| wallet)
+    }
+
+    /// Register the [FeatureParser] `T` and parse the feature of the stored wallets
+    pub fn register_feature_parser<T: FeatureParser + 'static>(&mut self) -> &mut Self {
+        self.parsers.register::<T>();
+
+        let parsers = &self.parsers;
+        self.wallets
+            .iter_mut()
+            .for_each(|(_, _, wallet)| wallet.features.parse_registered(parsers));
+
+        self
+    }
+
+    /// The registered [FeatureParsers]
+    pub fn feature_parsers(&self) -> &FeatureParsers {
+        &self.parsers
+    }
+
+    /// Parse the features of the `wallet` that have a registered [FeatureParser]
+    pub fn parse_features(&self, wallet: &mut Wallet) {
+        wallet.features.parse_registered(&self.parsers);
+    }
+}
 
 /// Convenience type for `Rc<RefCell<StorageSchema>>;`
 pub type StorageType = Rc<RefCell<StorageSchema>>;
@@ -13,22 +122,41 @@ pub type StorageType = Rc<RefCell<StorageSchema>>;
 pub struct WalletStorage(StorageType);
 
 impl WalletStorage {
-    /// Clone the inner field  as `Rc<RefCell<HashMap<blake3::Hash, Wallet>>>`
+    /// Clone the inner field  as `Rc<RefCell<StorageSchema>>`
     pub fn clone_inner(&self) -> StorageType {
         Rc::clone(&self.0)
     }
 
-    /// Get all the wallets from storage
+    /// Get all the wallets from storage in the order they first registered
     pub fn get_wallets(&self) -> Vec<Wallet> {
-        self.0.borrow().values().cloned().collect::<Vec<Wallet>>()
+        self.0.borrow().wallets().cloned().collect::<Vec<Wallet>>()
     }
 
     /// Get a certain wallet by name from storage
     pub fn get_wallet(&self, wallet_name: &str) -> Option<Wallet> {
-        let storage_ref = self.0.borrow();
-        storage_ref
-            .get(&blake3::hash(wallet_name.to_lowercase().as_bytes()))
-            .cloned()
+        self.0.borrow().get(wallet_name).cloned()
+    }
+
+    /// Replace a registered wallet with an updated `wallet` of the same name,
+    /// returning the wallet it replaced. A wallet that is not registered is not added
+    pub fn update_wallet(&self, wallet: Wallet) -> Option<Wallet> {
+        self.0.borrow_mut().update(wallet)
+    }
+
+    /// Parse the features of the `wallet` that have a registered [FeatureParser]
+    pub fn parse_features(&self, wallet: &mut Wallet) {
+        self.0.borrow().parse_features(wallet);
+    }
+
+    /// Remove a wallet by name from storage, returning the removed wallet
+    pub fn remove_wallet(&self, wallet_name: &str) -> Option<Wallet> {
+        self.0.borrow_mut().remove(wallet_name)
+    }
+}
+
+impl From<StorageType> for WalletStorage {
+    fn from(value: StorageType) -> Self {
+        Self(value)
     }
 }
 
@@ -37,3 +165,48 @@ impl core::fmt::Debug for WalletStorage {
         write!(f, "{:?}", &*self.0.borrow())
     }
 }
+
+#[cfg(test)]
+mod storage_tests {
+    use wallet_adapter_common::WalletData;
+
+    use super::*;
+
+    // The wallets are never cloned since cloning a `JsValue` requires a wasm32 target
+    fn wallet(name: &str) -> Wallet {
+        Wallet {
+            data: WalletData::new().set_name(name),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn tracks_registration_ids() {
+        let mut storage = StorageSchema::default();
+        assert!(storage.insert(wallet("Alpha")).is_none());
+        assert!(storage.insert(wallet("Beta")).is_none());
+        assert_eq!(storage.registration_id("alpha"), Some(1));
+        assert_eq!(storage.registration_id("Beta"), Some(2));
+
+        // An update keeps the registration id
+        assert!(storage.update(wallet("ALPHA")).is_some());
+        assert!(storage.update(wallet("Gamma")).is_none());
+        assert_eq!(storage.registration_id("Alpha"), Some(1));
+        assert!(storage.get("Gamma").is_none());
+
+        // Registering again gets a new id and keeps the position
+        assert!(storage.insert(wallet("alpha")).is_some());
+        assert_eq!(storage.registration_id("Alpha"), Some(3));
+        assert_eq!(
+            storage
+                .wallets()
+                .map(|wallet| wallet.name())
+                .collect::<Vec<&str>>(),
+            ["alpha", "Beta"]
+        );
+
+        assert!(storage.remove("ALPHA").is_some());
+        assert_eq!(storage.registration_id("Alpha"), None);
+        assert!(storage.remove("Alpha").is_none());
+    }
+}
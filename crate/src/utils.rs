@@ -4,6 +4,9 @@ use web_sys::{
     wasm_bindgen::{JsCast, JsValue},
 };
 
+#[cfg(any(feature = "mock", feature = "provider"))]
+use web_sys::wasm_bindgen::prelude::Closure;
+
 use crate::{WalletError, WalletResult};
 
 /// Helper utilities
@@ -39,6 +42,29 @@ impl InnerUtils {
 
         Ok(WalletCommonUtils::signature(&signature_bytes))
     }
+
+    /// Create a JavaScript function that passes all the arguments it is called with
+    /// to the `callback` as an [Array]. Features like `solana:signTransaction` are
+    /// variadic while a [Closure] only receives the arguments it declares.
+    /// A [js_sys::Proxy] `apply` trap is used instead of `new Function()` so that
+    /// the function works on pages with a Content Security Policy that disallows `eval`
+    #[cfg(any(feature = "mock", feature = "provider"))]
+    pub(crate) fn variadic_function<F>(callback: F) -> WalletResult<JsValue>
+    where
+        F: Fn(Array) -> JsValue + 'static,
+    {
+        let apply = Closure::wrap(Box::new(
+            move |_target: JsValue, _this: JsValue, arguments: Array| callback(arguments),
+        ) as Box<dyn Fn(JsValue, JsValue, Array) -> JsValue>)
+        .into_js_value();
+
+        let mut handler = Reflection::new_object();
+        handler.set_object(&"apply".into(), &apply)?;
+
+        let target = Closure::wrap(Box::new(|| {}) as Box<dyn Fn()>).into_js_value();
+
+        Ok(js_sys::Proxy::new(&target, handler.get_inner().unchecked_ref()).into())
+    }
 }
 
 /// Perform reflection on a [JsValue]
@@ -222,31 +248,12 @@ impl Reflection {
         Self::new(js_value)?.into_array()
     }
 
-    pub(crate) fn vec_string_and_filter(
-        &self,
-        key: &str,
-        filter: &str,
-    ) -> WalletResult<Vec<String>> {
-        let js_value = self.reflect_inner(key)?;
-
-        let to_js_array = Reflection::new(js_value)?.into_array()?;
-
-        to_js_array
+    /// Reflect the `key` from the value of [Self](Reflection) and return the
+    /// reflected value as a [Vec of String](Vec<String>), erroring if a value is not a string
+    pub(crate) fn vec_string(&self, key: &str) -> WalletResult<Vec<String>> {
+        self.reflect_js_array(key)?
             .iter()
-            .map(|value| {
-                value.as_string().ok_or(WalletError::InternalError(format!(
-                    "{value:?} is not a JsString"
-                )))
-            })
-            .map(|value| {
-                let value = value?;
-
-                if value.starts_with(filter) {
-                    Ok(value)
-                } else {
-                    Err(WalletError::UnsupportedChain(value.to_string()))
-                }
-            })
+            .map(|value| Self::get_string(&value))
             .collect::<WalletResult<Vec<String>>>()
     }
 
@@ -269,6 +276,12 @@ impl Reflection {
             .collect::<WalletResult<Vec<String>>>()
     }
 
+    /// Check if the `key` of the value of [Self](Reflection) is neither null nor undefined
+    pub fn contains_key(&self, key: &str) -> bool {
+        Reflect::get(&self.0, &key.into())
+            .is_ok_and(|value| Self::check_is_undefined(&value).is_ok())
+    }
+
     /// Check if [Self](Reflection) is null or undefined
     pub fn check_is_undefined(value: &JsValue) -> WalletResult<()> {
         if value.is_undefined() || value.is_null() {
@@ -342,3 +355,32 @@ impl Clone for Reflection {
         Reflection(self.0.clone())
     }
 }
+
+/// Poll a `future` until it is ready, used by the host tests which do not have an executor.
+/// The futures tested only wait for other futures polled alongside them
+#[cfg(test)]
+pub(crate) fn block_on<T>(future: impl core::future::Future<Output = T>) -> T {
+    use core::task::{Context, Poll};
+    use std::{
+        sync::Arc,
+        task::{Wake, Waker},
+    };
+
+    struct NoopWaker;
+
+    impl Wake for NoopWaker {
+        fn wake(self: Arc<Self>) {}
+    }
+
+    let waker = Waker::from(Arc::new(NoopWaker));
+    let mut context = Context::from_waker(&waker);
+    let mut future = core::pin::pin!(future);
+
+    for _ in 0..100 {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+            return output;
+        }
+    }
+
+    panic!("The future did not settle")
+}
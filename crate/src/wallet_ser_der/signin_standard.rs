@@ -249,6 +249,74 @@ impl SigninInput {
         Ok(signin_input_object.take())
     }
 
+    /// Parses the sign in input object an app sends to a wallet back into [Self].
+    /// The expiration and not before times are not checked against the current time
+    #[cfg(any(feature = "mock", feature = "provider"))]
+    pub(crate) fn from_object(input: &Reflection) -> WalletResult<Self> {
+        let mut signin_input = Self::default();
+
+        if let Some(domain) = input.string_optional("domain")? {
+            signin_input.0.set_domain(&domain);
+        }
+        if let Some(address) = input.string_optional("address")? {
+            signin_input.0.set_address(&address)?;
+        }
+        if let Some(statement) = input.string_optional("statement")? {
+            signin_input.0.set_statement(&statement);
+        }
+        if let Some(uri) = input.string_optional("uri")? {
+            signin_input.0.set_uri(&uri);
+        }
+        if let Some(version) = input.string_optional("version")? {
+            signin_input.0.set_version(&version);
+        }
+        if let Some(chain_id) = input.string_optional("chainId")? {
+            signin_input
+                .0
+                .set_chain_id(Cluster::try_from(chain_id.as_str())?);
+        }
+        if let Some(nonce) = input.string_optional("nonce")? {
+            signin_input.0.set_custom_nonce(&nonce)?;
+        }
+        if let Some(issued_at) = input.string_optional("issuedAt")? {
+            signin_input
+                .0
+                .set_issued_at(Self::parse_iso8601(issued_at)?);
+        }
+        if let Some(expiration_time) = input.string_optional("expirationTime")? {
+            signin_input
+                .0
+                .set_expiration_time(UNIX_EPOCH, Self::parse_iso8601(expiration_time)?)?;
+        }
+        if let Some(not_before) = input.string_optional("notBefore")? {
+            signin_input
+                .0
+                .set_not_before_time(UNIX_EPOCH, Self::parse_iso8601(not_before)?)?;
+        }
+        if let Some(request_id) = input.string_optional("requestId")? {
+            signin_input.0.set_request_id(&request_id);
+        }
+        if let Ok(resources) = input.vec_string_accept_undefined("resources") {
+            resources.iter().for_each(|resource| {
+                signin_input.0.add_resource(resource);
+            });
+        }
+
+        Ok(signin_input)
+    }
+
+    #[cfg(any(feature = "mock", feature = "provider"))]
+    fn parse_iso8601(value: String) -> WalletResult<SystemTime> {
+        humantime::parse_rfc3339(&value).or(Err(WalletError::InvalidISO8601Timestamp(value)))
+    }
+
+    /// Build the Sign In With Solana (SIWS) message a wallet signs.
+    /// The `domain` and `address` fields of [Self] take precedence over
+    /// the `default_domain` and `default_address` determined by the wallet
+    pub fn message(&self, default_domain: &str, default_address: &str) -> String {
+        self.0.message(default_domain, default_address)
+    }
+
     /// An EIP-4361 Request ID.
     /// In addition to using nonce to avoid replay attacks,
     /// dapps can also choose to include a unique signature in the requestId .
@@ -17,9 +17,14 @@ impl Connect {
         )?))
     }
 
-    /// Connect to a wallet by calling the callback function
-    pub(crate) async fn call_connect(&self) -> WalletResult<WalletAccount> {
-        let outcome = self.0.callback.call0(&JsValue::from_bool(false))?;
+    /// Connect to a wallet by calling the callback function.
+    /// A `silent` request asks the wallet to only return accounts the app
+    /// is already authorized to use without prompting the user
+    pub(crate) async fn call_connect(&self, silent: bool) -> WalletResult<Vec<WalletAccount>> {
+        let mut input = Reflection::new_object();
+        input.set_object(&"silent".into(), &JsValue::from_bool(silent))?;
+
+        let outcome = self.0.callback.call1(&JsValue::null(), input.get_inner())?;
 
         let outcome = js_sys::Promise::resolve(&outcome);
 
@@ -28,19 +33,16 @@ impl Connect {
             .map(|success| {
                 let get_accounts = Reflection::new(success)?.reflect_js_array("accounts")?;
 
-                let wallet_account = get_accounts
+                let wallet_accounts = get_accounts
                     .into_iter()
                     .map(|raw_account| WalletAccount::parse(Reflection::new(raw_account)?))
-                    .collect::<WalletResult<Vec<WalletAccount>>>()
-                    .map(|mut accounts| {
-                        if accounts.is_empty() {
-                            Err(WalletError::ConnectHasNoAccounts)
-                        } else {
-                            Ok(accounts.remove(0))
-                        }
-                    })??;
-
-                Ok(wallet_account)
+                    .collect::<WalletResult<Vec<WalletAccount>>>()?;
+
+                if wallet_accounts.is_empty() {
+                    Err(WalletError::ConnectHasNoAccounts)
+                } else {
+                    Ok(wallet_accounts)
+                }
             })
             .map_err(|error| {
                 let value: WalletError = error.into();
@@ -49,3 +51,71 @@ impl Connect {
             })?
     }
 }
+
+#[cfg(test)]
+#[cfg(feature = "mock")]
+#[cfg(target_arch = "wasm32")]
+mod connect_tests {
+    use wasm_bindgen_test::*;
+
+    use crate::{
+        mock::test_utils::{mock_with_account, registered},
+        MockFeature, MockOutcome, MockWallet, WalletError,
+    };
+
+    #[wasm_bindgen_test]
+    async fn connect_approved() {
+        let (mock, mut adapter) = registered(mock_with_account("Mock Connect"));
+
+        let account = adapter.connect_by_name(mock.name()).await.unwrap();
+
+        assert_eq!(account.public_key(), mock.accounts()[0].public_key());
+        assert!(adapter.is_connected().await);
+        assert_eq!(mock.calls_for(MockFeature::Connect).len(), 1);
+    }
+
+    #[wasm_bindgen_test]
+    async fn connect_rejected() {
+        let (mock, mut adapter) = registered(mock_with_account("Mock Connect Rejected"));
+        mock.queue_outcome(MockFeature::Connect, MockOutcome::user_rejected());
+
+        let outcome = adapter.connect_by_name(mock.name()).await;
+
+        assert!(matches!(outcome, Err(WalletError::WalletConnectError(_))));
+        assert!(!adapter.is_connected().await);
+    }
+
+    #[wasm_bindgen_test]
+    async fn connect_without_accounts() {
+        let (mock, mut adapter) = registered(MockWallet::new("Mock No Accounts"));
+
+        assert_eq!(
+            adapter.connect_by_name(mock.name()).await,
+            Err(WalletError::ConnectHasNoAccounts)
+        );
+    }
+
+    #[wasm_bindgen_test]
+    async fn rejected_connect_keeps_previous_wallet() {
+        let (mock, mut adapter) = registered(mock_with_account("Mock Keeps Connection"));
+        let other = mock_with_account("Mock Rejects Connection");
+        other.register(adapter.window()).unwrap();
+
+        let account = adapter.connect_by_name(mock.name()).await.unwrap();
+        assert_eq!(mock.listener_count(), 1);
+
+        other.queue_outcome(MockFeature::Connect, MockOutcome::user_rejected());
+        assert!(adapter.connect_by_name(other.name()).await.is_err());
+
+        let connection_info = adapter.connection_info().await;
+        assert_eq!(
+            connection_info.connected_wallet().unwrap().name(),
+            mock.name()
+        );
+        assert_eq!(connection_info.connected_account().unwrap(), &account);
+        drop(connection_info);
+
+        assert_eq!(mock.listener_count(), 1);
+        assert_eq!(other.listener_count(), 0);
+    }
+}
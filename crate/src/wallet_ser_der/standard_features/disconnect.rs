@@ -33,3 +33,48 @@ impl Disconnect {
         Ok(())
     }
 }
+
+#[cfg(test)]
+#[cfg(feature = "mock")]
+#[cfg(target_arch = "wasm32")]
+mod disconnect_tests {
+    use wasm_bindgen_test::*;
+
+    use crate::{
+        mock::test_utils::{mock_with_account, registered},
+        MockFeature, MockOutcome, WalletError, WalletEvent,
+    };
+
+    #[wasm_bindgen_test]
+    async fn disconnect_calls_wallet() {
+        let (mock, mut adapter) = registered(mock_with_account("Mock Disconnect"));
+        let events = adapter.subscribe();
+
+        adapter.connect_by_name(mock.name()).await.unwrap();
+        mock.queue_outcome(MockFeature::Disconnect, MockOutcome::user_rejected());
+
+        assert!(matches!(
+            adapter.disconnect().await,
+            Err(WalletError::WalletDisconnectError(_))
+        ));
+        assert!(adapter.is_connected().await);
+        assert_eq!(mock.listener_count(), 1);
+
+        adapter.disconnect().await.unwrap();
+        assert!(!adapter.is_connected().await);
+        assert_eq!(mock.listener_count(), 0);
+        assert_eq!(mock.calls_for(MockFeature::Disconnect).len(), 2);
+        loop {
+            if let WalletEvent::Disconnected = events.recv().await.unwrap() {
+                break;
+            }
+        }
+
+        adapter.connect_by_name(mock.name()).await.unwrap();
+        mock.queue_outcome(MockFeature::Disconnect, MockOutcome::user_rejected());
+
+        assert!(adapter.force_disconnect().await.is_err());
+        assert!(!adapter.is_connected().await);
+        assert_eq!(mock.listener_count(), 0);
+    }
+}
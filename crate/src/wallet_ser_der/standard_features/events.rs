@@ -1,11 +1,13 @@
 use std::{future::Future, pin::Pin};
 
-use async_channel::Receiver;
-use web_sys::wasm_bindgen::{prelude::Closure, JsValue};
+use web_sys::{
+    js_sys::Function,
+    wasm_bindgen::{prelude::Closure, JsValue},
+};
 
 use crate::{
-    ConnectionInfoInner, Reflection, SemverVersion, StandardFunction, WalletAccount, WalletError,
-    WalletEvent, WalletEventSender, WalletResult,
+    ConnectionInfoInner, Reflection, SemverVersion, StandardFunction, WalletAccount, WalletEvent,
+    WalletEventSender, WalletResult, WalletStorage,
 };
 
 /// `standard:events` struct containing the `version` and `callback`
@@ -24,13 +26,14 @@ impl StandardEvents {
         }))
     }
 
+    /// Subscribe to the `change` event using `[standard:events].on`
     pub(crate) async fn call_on_event(
         &self,
         connection_info: ConnectionInfoInner,
+        storage: WalletStorage,
         wallet_name: String,
         sender: WalletEventSender,
-        stop_signal: Receiver<()>,
-    ) -> WalletResult<()> {
+    ) -> WalletResult<EventsSubscription> {
         let sender2 = sender.clone();
 
         let on_account_change = Closure::wrap(Box::new(move |value: JsValue| {
@@ -42,49 +45,73 @@ impl StandardEvents {
             );
 
             let connection_info_inner = connection_info.clone();
+            let storage_inner = storage.clone();
             let sender_inner = sender2.clone();
 
             wasm_bindgen_futures::spawn_local(async move {
                 let reflect_accounts =
-                    send_wallet_event_error()(Reflection::new(value), sender_inner.clone())
+                    match send_wallet_event_error()(Reflection::new(value), sender_inner.clone())
                         .await
-                        .unwrap(); // Never fails
-                let mut get_accounts = send_wallet_event_error()(
+                    {
+                        Ok(reflect_accounts) => reflect_accounts,
+                        Err(_) => return,
+                    };
+
+                let _ = send_wallet_event_error()(
+                    Self::update_wallet(
+                        &reflect_accounts,
+                        &wallet_name,
+                        &connection_info_inner,
+                        &storage_inner,
+                        sender_inner.clone(),
+                    )
+                    .await,
+                    sender_inner.clone(),
+                )
+                .await;
+
+                // The `change` event might only carry the chains or features
+                if !reflect_accounts.contains_key("accounts") {
+                    return;
+                }
+
+                let get_accounts = match send_wallet_event_error()(
                     reflect_accounts.reflect_js_array("accounts"),
                     sender_inner.clone(),
                 )
                 .await
-                .unwrap()
-                .to_vec(); // Never fails
+                {
+                    Ok(accounts) => accounts.to_vec(),
+                    Err(_) => return,
+                };
 
-                let processed_wallet_account = if !get_accounts.is_empty() {
-                    let first_account = send_wallet_event_error()(
-                        Reflection::new(get_accounts.remove(0)),
-                        sender_inner.clone(),
-                    )
-                    .await
-                    .unwrap(); // Never fails
+                let mut processed_wallet_accounts = Vec::<WalletAccount>::new();
 
-                    let account_processing = send_wallet_event_error()(
-                        WalletAccount::parse(first_account),
-                        sender_inner.clone(),
-                    )
-                    .await
-                    .unwrap(); //Never fails
-                    web_sys::console::error_2(
-                        &"PRE ACCOUNT PROCESSING".into(),
-                        &format!("{account_processing:?}").into(),
-                    );
+                for account in get_accounts {
+                    let account_processing = match Reflection::new(account)
+                        .and_then(WalletAccount::parse)
+                    {
+                        Ok(account_processing) => account_processing,
+                        Err(error) => {
+                            let _ =
+                                send_wallet_event_error::<()>()(Err(error), sender_inner.clone())
+                                    .await;
 
-                    Some(account_processing)
-                } else {
-                    Option::None
-                };
+                            return;
+                        }
+                    };
+
+                    processed_wallet_accounts.push(account_processing);
+                }
 
                 connection_info_inner
                     .write()
                     .await
-                    .emit_wallet_event(&wallet_name, processed_wallet_account, sender_inner.clone())
+                    .emit_wallet_event(
+                        &wallet_name,
+                        processed_wallet_accounts,
+                        sender_inner.clone(),
+                    )
                     .await
             });
         }) as Box<dyn Fn(_)>);
@@ -92,38 +119,81 @@ impl StandardEvents {
         let on_account_change_fn =
             Reflection::new(on_account_change.into_js_value())?.into_function()?;
 
-        let on_event_fn = self.0.callback.clone();
+        let off_fn = self.0.callback.call2(
+            &JsValue::null(),
+            &"change".into(),
+            &on_account_change_fn.into(),
+        )?;
 
-        wasm_bindgen_futures::spawn_local(async move {
-            while (stop_signal.recv().await).is_ok() {
-                let invoke_outcome = on_event_fn
-                    .call2(
-                        &JsValue::null(),
-                        &"change".into(),
-                        &on_account_change_fn.clone().into(),
-                    )
-                    .map_err(|error| {
-                        let into_error: WalletError = error.into();
+        Ok(EventsSubscription(
+            Reflection::new(off_fn)?.into_function()?,
+        ))
+    }
+
+    /// Update the wallet in `storage` and `connection_info` if the `change` event
+    /// carries its `chains` or `features`, then emit [WalletEvent::ChainsChanged]
+    /// and [WalletEvent::FeaturesChanged] for what changed
+    async fn update_wallet(
+        properties: &Reflection,
+        wallet_name: &str,
+        connection_info: &ConnectionInfoInner,
+        storage: &WalletStorage,
+        sender: WalletEventSender,
+    ) -> WalletResult<()> {
+        let has_chains = properties.contains_key("chains");
+        let has_features = properties.contains_key("features");
 
-                        into_error
-                    });
+        if !has_chains && !has_features {
+            return Ok(());
+        }
 
-                send_wallet_event_error()(invoke_outcome, sender.clone())
-                    .await
-                    .unwrap();
-            }
-        });
+        let mut connection_info = connection_info.write().await;
+        let connected_wallet = connection_info
+            .connected_wallet_raw()
+            .filter(|wallet| wallet.name() == wallet_name)
+            .cloned();
+        let is_connected = connected_wallet.is_some();
+
+        let mut wallet = match connected_wallet.or_else(|| storage.get_wallet(wallet_name)) {
+            Some(wallet) => wallet,
+            None => return Ok(()),
+        };
+
+        let chains_changed = has_chains && wallet.update_chains(properties.vec_string("chains")?);
+        let features_changed = has_features && wallet.update_features(properties)?;
+        if has_features {
+            storage.parse_features(&mut wallet);
+        }
+
+        storage.update_wallet(wallet.clone());
+        if is_connected {
+            connection_info.set_wallet(wallet.clone());
+        }
+        drop(connection_info);
+
+        if chains_changed {
+            sender.publish(WalletEvent::ChainsChanged(Box::new(wallet.clone())));
+        }
+
+        if features_changed {
+            sender.publish(WalletEvent::FeaturesChanged(Box::new(wallet)));
+        }
 
         Ok(())
     }
 }
 
-pub(crate) async fn send_wallet_event(wallet_event: WalletEvent, sender: WalletEventSender) {
-    if let Err(error) = sender.clone().send(wallet_event).await {
-        web_sys::console::log_2(
-            &"BACKGROUND TASK ERROR: [standard:events]on() > ".into(),
-            &format!("{error:?}").into(),
-        );
+/// The function returned by `[standard:events].on` that removes the `change` listener
+/// of the connected wallet
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EventsSubscription(Function);
+
+impl EventsSubscription {
+    /// Remove the listener
+    pub fn unsubscribe(&self) -> WalletResult<()> {
+        self.0.call0(&JsValue::null())?;
+
+        Ok(())
     }
 }
 
@@ -144,15 +214,7 @@ where
                         &format!("{error:?}").into(),
                     );
 
-                    if let Err(channel_error) = sender
-                        .send(WalletEvent::BackgroundTaskError(error.clone()))
-                        .await
-                    {
-                        web_sys::console::log_2(
-                            &"Encountered error while sending a wallet event: ".into(),
-                            &format!("{channel_error:?}").into(),
-                        );
-                    }
+                    sender.publish(WalletEvent::BackgroundTaskError(error.clone()));
 
                     Err(())
                 }
@@ -160,3 +222,137 @@ where
         })
     }
 }
+
+#[cfg(test)]
+#[cfg(feature = "mock")]
+#[cfg(target_arch = "wasm32")]
+mod standard_events_tests {
+    use wasm_bindgen_test::*;
+    use web_sys::wasm_bindgen::JsValue;
+
+    use crate::{
+        mock::test_utils::{mock_with_account, registered},
+        MockAccount, MockFeature, MockOutcome, WalletEvent,
+    };
+
+    #[wasm_bindgen_test]
+    async fn subscribes_to_change_events() {
+        let second_account = MockAccount::new([9u8; 32]);
+        let mock = mock_with_account("Mock Subscribe").add_account(second_account.clone());
+        let (mock, mut adapter) = registered(mock);
+        let other = mock_with_account("Mock Subscribe Other");
+        other.register(adapter.window()).unwrap();
+        let events = adapter.subscribe();
+
+        adapter.connect_by_name(mock.name()).await.unwrap();
+        assert_eq!(mock.listener_count(), 1);
+
+        mock.emit_accounts_changed(std::slice::from_ref(&second_account))
+            .unwrap();
+        loop {
+            if let WalletEvent::AccountChanged(account) = events.recv().await.unwrap() {
+                assert_eq!(account.address(), second_account.address());
+                break;
+            }
+        }
+
+        adapter.connect_by_name(mock.name()).await.unwrap();
+        assert_eq!(mock.listener_count(), 1);
+
+        adapter.connect_by_name(other.name()).await.unwrap();
+        assert_eq!(mock.listener_count(), 0);
+        assert_eq!(other.listener_count(), 1);
+
+        adapter.disconnect().await.unwrap();
+        assert_eq!(other.listener_count(), 0);
+    }
+
+    #[wasm_bindgen_test]
+    async fn revoking_every_account_disconnects() {
+        let (mock, mut adapter) = registered(mock_with_account("Mock Revokes Accounts"));
+        let events = adapter.subscribe();
+        adapter.connect_by_name(mock.name()).await.unwrap();
+        assert_eq!(mock.listener_count(), 1);
+
+        mock.emit_accounts_changed(&[]).unwrap();
+        loop {
+            if let WalletEvent::Disconnected = events.recv().await.unwrap() {
+                break;
+            }
+        }
+
+        let connection_info = adapter.connection_info().await;
+        assert!(connection_info.connected_wallet().is_err());
+        assert!(connection_info.connected_account().is_err());
+        assert!(connection_info.accounts().is_empty());
+        drop(connection_info);
+
+        assert!(!adapter.is_connected().await);
+        assert_eq!(mock.listener_count(), 0);
+    }
+
+    #[wasm_bindgen_test]
+    async fn connects_when_subscribing_fails() {
+        let (mock, mut adapter) = registered(mock_with_account("Mock Subscribe Fails"));
+        mock.set_outcome(
+            MockFeature::Events,
+            MockOutcome::Resolve(JsValue::undefined()),
+        );
+        let events = adapter.subscribe();
+
+        let account = adapter.connect_by_name(mock.name()).await.unwrap();
+        assert!(adapter.is_connected().await);
+        assert_eq!(mock.listener_count(), 0);
+        assert_eq!(adapter.recently_used_wallet().as_deref(), Some(mock.name()));
+
+        assert_eq!(
+            events.recv().await.unwrap(),
+            WalletEvent::Connected(account)
+        );
+        assert!(matches!(
+            events.recv().await.unwrap(),
+            WalletEvent::BackgroundTaskError(_)
+        ));
+    }
+
+    #[wasm_bindgen_test]
+    async fn updates_chains_and_features_on_change() {
+        let (mock, mut adapter) = registered(mock_with_account("Mock Live Update"));
+        let events = adapter.subscribe();
+
+        adapter.connect_by_name(mock.name()).await.unwrap();
+        assert!(adapter.get_wallet(mock.name()).unwrap().mainnet());
+
+        mock.emit_chains_changed(&["solana:devnet"]).unwrap();
+        let wallet = loop {
+            if let WalletEvent::ChainsChanged(wallet) = events.recv().await.unwrap() {
+                break wallet;
+            }
+        };
+        assert!(!wallet.mainnet());
+        assert!(wallet.devnet());
+        assert!(!adapter.get_wallet(mock.name()).unwrap().mainnet());
+        assert!(!adapter
+            .connection_info()
+            .await
+            .connected_wallet()
+            .unwrap()
+            .mainnet());
+
+        let features = MockFeature::ALL
+            .iter()
+            .filter(|feature| **feature != MockFeature::SignIn)
+            .copied()
+            .collect::<Vec<MockFeature>>();
+        mock.emit_features_changed(&features).unwrap();
+        let wallet = loop {
+            if let WalletEvent::FeaturesChanged(wallet) = events.recv().await.unwrap() {
+                break wallet;
+            }
+        };
+        assert!(!wallet.solana_signin());
+        assert!(wallet.solana_sign_message());
+        assert!(!adapter.get_wallet(mock.name()).unwrap().solana_signin());
+        assert!(adapter.is_connected().await);
+    }
+}
@@ -1,15 +1,18 @@
 use wallet_adapter_common::{
     feature_support::FeatureSupport,
     standardized_events::{
+        SOLANA_SIGN_AND_SEND_ALL_TRANSACTIONS_IDENTIFIER,
         SOLANA_SIGN_AND_SEND_TRANSACTION_IDENTIFIER, SOLANA_SIGN_IN_IDENTIFIER,
         SOLANA_SIGN_MESSAGE_IDENTIFIER, SOLANA_SIGN_TRANSACTION_IDENTIFIER,
         STANDARD_CONNECT_IDENTIFIER, STANDARD_DISCONNECT_IDENTIFIER, STANDARD_EVENTS_IDENTIFIER,
     },
 };
 
+use std::rc::Rc;
+
 use crate::{
-    Connect, Disconnect, Reflection, SemverVersion, SignIn, SignMessage, SignTransaction,
-    StandardEvents, WalletError, WalletResult,
+    Connect, Disconnect, FeatureParser, FeatureParsers, ParsedFeatures, RawFeature, Reflection,
+    SemverVersion, SignIn, SignMessage, SignTransaction, StandardEvents, WalletResult,
 };
 
 /// All the features of `standard` and `solana` events as specified
@@ -26,6 +29,8 @@ pub struct Features {
     pub(crate) events: StandardEvents,
     /// solana:signAndSendTransaction
     pub(crate) sign_and_send_tx: SignTransaction,
+    /// solana:signAndSendAllTransactions
+    pub(crate) sign_and_send_all_tx: Option<SignTransaction>,
     /// solana:signTransaction
     pub(crate) sign_tx: SignTransaction,
     /// solana:signMessage
@@ -34,6 +39,14 @@ pub struct Features {
     pub(crate) sign_in: Option<SignIn>,
     /// Non-standard features
     extensions: Vec<String>,
+    /// `standard:` and `solana:` features not parsed by this library
+    unknown_features: Vec<String>,
+    /// The feature objects of extensions and unknown features
+    raw_features: Vec<RawFeature>,
+    /// The raw features parsed by the registered [FeatureParsers]
+    parsed_features: ParsedFeatures,
+    /// Standard features skipped because their version is not compatible
+    incompatible_features: Vec<(String, SemverVersion)>,
 }
 
 impl Features {
@@ -45,46 +58,65 @@ impl Features {
         let mut features = Features::default();
         let mut supported_features = FeatureSupport::default();
 
-        features_keys.into_iter().try_for_each(|feature| {
-            let inner_object = features_object.reflect_inner(&feature)?;
-            let inner_object = Reflection::new(inner_object)?;
-
-            if feature.starts_with("standard:") || feature.starts_with("solana:") {
-                let version = SemverVersion::from_jsvalue(&inner_object)?;
-
-                if feature == STANDARD_CONNECT_IDENTIFIER {
-                    features.connect = Connect::new(&inner_object, version)?;
-                    supported_features.connect = true;
-                } else if feature == STANDARD_DISCONNECT_IDENTIFIER {
-                    features.disconnect = Disconnect::new(&inner_object, version)?;
-                    supported_features.disconnect = true;
-                } else if feature == STANDARD_EVENTS_IDENTIFIER {
-                    features.events = StandardEvents::new(&inner_object, version)?;
-                    supported_features.events = true;
-                } else if feature == SOLANA_SIGN_AND_SEND_TRANSACTION_IDENTIFIER {
-                    features.sign_and_send_tx =
-                        SignTransaction::new_sign_and_send_tx(&inner_object, version)?;
-                    supported_features.sign_and_send_tx = true;
-                } else if feature == SOLANA_SIGN_TRANSACTION_IDENTIFIER {
-                    features.sign_tx = SignTransaction::new_sign_tx(&inner_object, version)?;
-                    supported_features.sign_tx = true;
-                } else if feature == SOLANA_SIGN_MESSAGE_IDENTIFIER {
-                    features.sign_message = SignMessage::new(&inner_object, version)?;
-                    supported_features.sign_message = true;
-                } else if feature == SOLANA_SIGN_IN_IDENTIFIER {
+        features_keys
+            .into_iter()
+            .try_for_each(|feature| -> WalletResult<()> {
+                let inner_object = features_object.reflect_inner(&feature)?;
+                let inner_object = Reflection::new(inner_object)?;
+
+                if let Some(requirement) = FeatureSupport::version_requirement(&feature) {
+                    let version = SemverVersion::from_jsvalue(&inner_object)?;
+
+                    if !FeatureSupport::is_compatible(&requirement, &version.0) {
+                        features.incompatible_features.push((feature, version));
+
+                        return Ok(());
+                    }
+
+                    if feature == STANDARD_CONNECT_IDENTIFIER {
+                        features.connect = Connect::new(&inner_object, version)?;
+                        supported_features.connect = true;
+                    } else if feature == STANDARD_DISCONNECT_IDENTIFIER {
+                        features.disconnect = Disconnect::new(&inner_object, version)?;
+                        supported_features.disconnect = true;
+                    } else if feature == STANDARD_EVENTS_IDENTIFIER {
+                        features.events = StandardEvents::new(&inner_object, version)?;
+                        supported_features.events = true;
+                    } else if feature == SOLANA_SIGN_AND_SEND_TRANSACTION_IDENTIFIER {
+                        features.sign_and_send_tx =
+                            SignTransaction::new_sign_and_send_tx(&inner_object, version)?;
+                        supported_features.sign_and_send_tx = true;
+                    } else if feature == SOLANA_SIGN_AND_SEND_ALL_TRANSACTIONS_IDENTIFIER {
+                        features.sign_and_send_all_tx.replace(
+                            SignTransaction::new_sign_and_send_all_tx(&inner_object, version)?,
+                        );
+                        supported_features.sign_and_send_all_tx = true;
+                    } else if feature == SOLANA_SIGN_TRANSACTION_IDENTIFIER {
+                        features.sign_tx = SignTransaction::new_sign_tx(&inner_object, version)?;
+                        supported_features.sign_tx = true;
+                    } else if feature == SOLANA_SIGN_MESSAGE_IDENTIFIER {
+                        features.sign_message = SignMessage::new(&inner_object, version)?;
+                        supported_features.sign_message = true;
+                    } else if feature == SOLANA_SIGN_IN_IDENTIFIER {
+                        features
+                            .sign_in
+                            .replace(SignIn::new(&inner_object, version)?);
+                        supported_features.sign_in = true;
+                    }
+                } else if feature.starts_with("standard:") || feature.starts_with("solana:") {
                     features
-                        .sign_in
-                        .replace(SignIn::new(&inner_object, version)?);
-                    supported_features.sign_in = true;
+                        .raw_features
+                        .push(RawFeature::new(&feature, &inner_object));
+                    features.unknown_features.push(feature);
                 } else {
-                    return Err(WalletError::UnsupportedWalletFeature(feature));
+                    features
+                        .raw_features
+                        .push(RawFeature::new(&feature, &inner_object));
+                    features.extensions.push(feature);
                 }
-            } else {
-                features.extensions.push(feature);
-            }
 
-            Ok(())
-        })?;
+                Ok(())
+            })?;
 
         Ok((features, supported_features))
     }
@@ -93,4 +125,99 @@ impl Features {
     pub fn extensions(&self) -> &[String] {
         &self.extensions
     }
+
+    /// Get the `standard:` and `solana:` features that are not parsed by this library.
+    /// Their feature objects are available using [Self::raw_feature]
+    pub fn unknown_features(&self) -> &[String] {
+        &self.unknown_features
+    }
+
+    /// Get the feature object of an extension or an unknown feature
+    pub fn raw_feature(&self, identifier: &str) -> Option<&RawFeature> {
+        self.raw_features
+            .iter()
+            .find(|feature| feature.identifier() == identifier)
+    }
+
+    /// Get the feature objects of all extensions and unknown features
+    pub fn raw_features(&self) -> &[RawFeature] {
+        &self.raw_features
+    }
+
+    /// Parse the feature registered by the [FeatureParser] `T`.
+    /// Returns [None] if the wallet does not have the feature
+    pub fn get<T: FeatureParser>(&self) -> Option<WalletResult<T>> {
+        self.raw_feature(T::IDENTIFIER).map(T::parse)
+    }
+
+    /// Get the feature parsed when the wallet registered by the [FeatureParser] `T`
+    /// registered using [WalletAdapter::register_feature_parser](crate::WalletAdapter::register_feature_parser).
+    /// Returns [None] if `T` is not registered or the wallet does not have the feature
+    pub fn parsed<T: FeatureParser + 'static>(&self) -> Option<WalletResult<Rc<T>>> {
+        self.parsed_features.get::<T>()
+    }
+
+    /// Parse the raw features that have a parser in `parsers`
+    pub(crate) fn parse_registered(&mut self, parsers: &FeatureParsers) {
+        self.parsed_features = parsers.parse(&self.raw_features);
+    }
+
+    /// Get the standard features, and their versions, that were skipped because their version
+    /// is not [compatible](FeatureSupport::is_compatible) with the
+    /// [requirement](FeatureSupport::version_requirement) of this library
+    pub fn incompatible_features(&self) -> &[(String, SemverVersion)] {
+        &self.incompatible_features
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "mock")]
+#[cfg(target_arch = "wasm32")]
+mod features_tests {
+    use wasm_bindgen_test::*;
+
+    use crate::{mock::test_utils::mock_with_account, MockFeature, Wallet};
+
+    #[wasm_bindgen_test]
+    fn gates_incompatible_feature_versions() {
+        let mock = mock_with_account("Mock Feature Versions")
+            .set_version("1.1.0-beta.1")
+            .set_feature_version("2.0.0");
+
+        let wallet = Wallet::from_jsvalue(mock.to_jsvalue().unwrap()).unwrap();
+        assert_eq!(wallet.version().to_string(), "1.1.0-beta.1");
+        assert!(!wallet.supports_wallet_standard());
+        assert!(!wallet.standard_connect());
+        assert!(!wallet.solana_sign_transaction());
+        assert_eq!(
+            wallet.features().incompatible_features().len(),
+            MockFeature::ALL.len()
+        );
+        assert!(wallet
+            .features()
+            .incompatible_features()
+            .iter()
+            .all(|(_, version)| version.major() == 2));
+
+        let compatible = Wallet::from_jsvalue(
+            mock_with_account("Mock Feature Versions Compatible")
+                .set_feature_version("1.2.0")
+                .to_jsvalue()
+                .unwrap(),
+        )
+        .unwrap();
+        assert!(compatible.supports_wallet_standard());
+        assert!(compatible.standard_connect());
+        assert!(compatible.features().incompatible_features().is_empty());
+
+        let pre_release = Wallet::from_jsvalue(
+            mock_with_account("Mock Feature Versions Pre-release")
+                .set_feature_version("1.0.0-beta.2")
+                .to_jsvalue()
+                .unwrap(),
+        )
+        .unwrap();
+        assert!(pre_release.standard_connect());
+        assert!(pre_release.features().incompatible_features().is_empty());
+    }
 }
@@ -21,3 +21,6 @@ pub use sign_tx::*;
 
 mod standard_fn;
 pub use standard_fn::*;
+
+mod raw_feature;
+pub use raw_feature::*;
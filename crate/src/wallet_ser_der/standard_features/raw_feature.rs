@@ -0,0 +1,495 @@
+use core::{
+    any::{Any, TypeId},
+    hash::Hash,
+};
+use std::rc::Rc;
+
+use web_sys::{js_sys, wasm_bindgen::JsValue};
+
+use crate::{Reflection, SemverVersion, WalletError, WalletResult};
+
+/// A feature that is not parsed by this library, example a vendor extension like
+/// `phantom:...` or a `standard:` or `solana:` feature unknown to this library.
+/// The feature object is kept as is so it can be parsed later by a [FeatureParser]
+#[derive(Debug, Clone, PartialEq)]
+pub struct RawFeature {
+    identifier: String,
+    version: Option<SemverVersion>,
+    object: JsValue,
+}
+
+impl RawFeature {
+    /// Keep the feature object for the feature `identifier`.
+    /// The version is optional since extensions are not required to have one
+    pub(crate) fn new(identifier: &str, reflection: &Reflection) -> Self {
+        Self {
+            identifier: identifier.to_string(),
+            version: SemverVersion::from_jsvalue(reflection).ok(),
+            object: reflection.get_inner().clone(),
+        }
+    }
+
+    /// The identifier of the feature, example `phantom:signAndSendX`
+    pub fn identifier(&self) -> &str {
+        &self.identifier
+    }
+
+    /// The version of the feature if the feature object has a valid `version`
+    pub fn version(&self) -> Option<&SemverVersion> {
+        self.version.as_ref()
+    }
+
+    /// The feature object as exposed by the wallet
+    pub fn object(&self) -> &JsValue {
+        &self.object
+    }
+
+    /// Get a `Reflection` of the feature object to read its fields
+    pub fn reflection(&self) -> WalletResult<Reflection> {
+        Reflection::new(self.object.clone())
+    }
+
+    /// Call the function `method` of the feature object with `input` as the only argument.
+    /// The function is called with the feature object as `this` and
+    /// if it returns a promise, the promise is awaited
+    pub async fn call(&self, method: &str, input: JsValue) -> WalletResult<JsValue> {
+        let callback = self.reflection()?.get_function(method).or(Err(
+            WalletError::MissingExtensionMethod {
+                feature: self.identifier.clone(),
+                method: method.to_string(),
+            },
+        ))?;
+
+        let outcome = callback.call1(&self.object, &input)?;
+
+        let outcome = js_sys::Promise::resolve(&outcome);
+
+        Ok(wasm_bindgen_futures::JsFuture::from(outcome).await?)
+    }
+}
+
+impl Eq for RawFeature {}
+
+#[allow(clippy::non_canonical_partial_ord_impl)]
+impl PartialOrd for RawFeature {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for RawFeature {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.identifier
+            .cmp(&other.identifier)
+            .then_with(|| self.version.cmp(&other.version))
+    }
+}
+
+impl Hash for RawFeature {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.identifier.hash(state);
+        self.version.hash(state);
+    }
+}
+
+/// Implemented by applications to register a parser for a namespaced feature
+/// that is not parsed by this library using
+/// [WalletAdapter::register_feature_parser](crate::WalletAdapter::register_feature_parser).
+/// The typed feature is then obtained from the wallet using [Features::parsed](crate::Features::parsed)
+/// or parsed on demand using [Features::get](crate::Features::get).
+/// ```rust
+/// use wallet_adapter::{FeatureParser, RawFeature, WalletResult};
+/// use wallet_adapter::web_sys::js_sys::Function;
+///
+/// struct SignAndSendX(Function);
+///
+/// impl FeatureParser for SignAndSendX {
+///     const IDENTIFIER: &'static str = "phantom:signAndSendX";
+///
+///     fn parse(feature: &RawFeature) -> WalletResult<Self> {
+///         Ok(Self(feature.reflection()?.get_function("signAndSendX")?))
+///     }
+/// }
+/// ```
+pub trait FeatureParser: Sized {
+    /// The identifier of the feature, example `solana:signAndSendAllTransactions`
+    const IDENTIFIER: &'static str;
+
+    /// Parse the feature object exposed by the wallet
+    fn parse(feature: &RawFeature) -> WalletResult<Self>;
+}
+
+// Parses a [RawFeature] into the [FeatureParser] it was registered for
+type ParseFn = fn(&RawFeature) -> WalletResult<ParsedFeature>;
+
+// A feature parsed by a [FeatureParser]
+type ParsedFeature = Rc<dyn Any>;
+
+/// The registered [FeatureParser]s, each feature is parsed when a wallet registers
+#[derive(Clone, Default)]
+pub struct FeatureParsers(Vec<(TypeId, &'static str, ParseFn)>);
+
+impl FeatureParsers {
+    /// Register the [FeatureParser] `T`, registering it again has no effect
+    pub fn register<T: FeatureParser + 'static>(&mut self) -> &mut Self {
+        if !self.is_registered::<T>() {
+            self.0.push((TypeId::of::<T>(), T::IDENTIFIER, |feature| {
+                T::parse(feature).map(|parsed| Rc::new(parsed) as ParsedFeature)
+            }));
+        }
+
+        self
+    }
+
+    /// Check whether the [FeatureParser] `T` is registered
+    pub fn is_registered<T: FeatureParser + 'static>(&self) -> bool {
+        self.0
+            .iter()
+            .any(|(type_id, _, _)| *type_id == TypeId::of::<T>())
+    }
+
+    /// The identifiers of the features that have a registered parser
+    pub fn identifiers(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.0.iter().map(|(_, identifier, _)| *identifier)
+    }
+
+    /// Parse the `raw_features` that have a registered parser
+    pub(crate) fn parse(&self, raw_features: &[RawFeature]) -> ParsedFeatures {
+        let parsed = self
+            .0
+            .iter()
+            .filter_map(|(type_id, identifier, parse)| {
+                raw_features
+                    .iter()
+                    .find(|feature| feature.identifier() == *identifier)
+                    .map(|feature| (*type_id, *identifier, parse(feature)))
+            })
+            .collect();
+
+        ParsedFeatures(parsed)
+    }
+}
+
+impl core::fmt::Debug for FeatureParsers {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.identifiers()).finish()
+    }
+}
+
+impl PartialEq for FeatureParsers {
+    fn eq(&self, other: &Self) -> bool {
+        self.0
+            .iter()
+            .map(|(type_id, _, _)| type_id)
+            .eq(other.0.iter().map(|(type_id, _, _)| type_id))
+    }
+}
+
+impl Eq for FeatureParsers {}
+
+/// The features of a wallet parsed by the registered [FeatureParsers]
+#[derive(Clone, Default)]
+pub(crate) struct ParsedFeatures(Vec<(TypeId, &'static str, WalletResult<ParsedFeature>)>);
+
+impl ParsedFeatures {
+    /// Get the feature parsed by the [FeatureParser] `T`
+    pub(crate) fn get<T: FeatureParser + 'static>(&self) -> Option<WalletResult<Rc<T>>> {
+        self.0
+            .iter()
+            .find(|(type_id, _, _)| *type_id == TypeId::of::<T>())
+            .map(|(_, identifier, parsed)| {
+                parsed.clone().and_then(|parsed| {
+                    parsed
+                        .downcast::<T>()
+                        .or(Err(WalletError::FeatureParserMismatch(
+                            identifier.to_string(),
+                        )))
+                })
+            })
+    }
+
+    // The parsers and whether they parsed the feature, used to compare and hash the features
+    fn outcomes(&self) -> impl Iterator<Item = (&'static str, TypeId, bool)> + '_ {
+        self.0
+            .iter()
+            .map(|(type_id, identifier, parsed)| (*identifier, *type_id, parsed.is_ok()))
+    }
+}
+
+impl core::fmt::Debug for ParsedFeatures {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list()
+            .entries(
+                self.0
+                    .iter()
+                    .map(|(_, identifier, parsed)| (identifier, parsed.as_ref().map(|_| ()))),
+            )
+            .finish()
+    }
+}
+
+impl PartialEq for ParsedFeatures {
+    fn eq(&self, other: &Self) -> bool {
+        self.outcomes().eq(other.outcomes())
+    }
+}
+
+impl Eq for ParsedFeatures {}
+
+impl PartialOrd for ParsedFeatures {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ParsedFeatures {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.outcomes().cmp(other.outcomes())
+    }
+}
+
+impl Hash for ParsedFeatures {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.outcomes().for_each(|outcome| outcome.hash(state));
+    }
+}
+
+/// Describes the input and output of a function of an extension feature in Rust
+/// so that it can be called using [Wallet::call_extension_method](crate::Wallet::call_extension_method).
+/// ```rust
+/// use wallet_adapter::{ExtensionMethod, WalletError, WalletResult};
+/// use wallet_adapter::web_sys::wasm_bindgen::JsValue;
+///
+/// struct Ping(String);
+///
+/// impl ExtensionMethod for Ping {
+///     const FEATURE: &'static str = "phantom:ping";
+///     const METHOD: &'static str = "ping";
+///
+///     type Output = String;
+///
+///     fn to_input(&self) -> WalletResult<JsValue> {
+///         Ok(self.0.as_str().into())
+///     }
+///
+///     fn from_output(output: JsValue) -> WalletResult<Self::Output> {
+///         output
+///             .as_string()
+///             .ok_or(WalletError::Op("Expected a string".to_string()))
+///     }
+/// }
+/// ```
+pub trait ExtensionMethod {
+    /// The identifier of the feature, example `phantom:ping`
+    const FEATURE: &'static str;
+    /// The name of the function in the feature object
+    const METHOD: &'static str;
+
+    /// The type the output of the function is converted into
+    type Output;
+
+    /// Convert [Self] into the input of the function
+    fn to_input(&self) -> WalletResult<JsValue>;
+
+    /// Convert the output of the function, after resolving the promise, into [Self::Output]
+    fn from_output(output: JsValue) -> WalletResult<Self::Output>;
+}
+
+#[cfg(test)]
+#[cfg(feature = "mock")]
+#[cfg(target_arch = "wasm32")]
+mod raw_feature_tests {
+    use wasm_bindgen_test::*;
+    use web_sys::{
+        js_sys::Promise,
+        wasm_bindgen::{prelude::Closure, JsValue},
+    };
+
+    use crate::{
+        mock::test_utils::{mock_with_account, registered},
+        ExtensionMethod, FeatureParser, MockAccount, MockWallet, RawFeature, Reflection, Wallet,
+        WalletError, WalletResult,
+    };
+
+    struct MockPing(String);
+
+    impl FeatureParser for MockPing {
+        const IDENTIFIER: &'static str = "mock:ping";
+
+        fn parse(feature: &RawFeature) -> WalletResult<Self> {
+            Ok(Self(feature.reflection()?.string("reply")?))
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn keeps_unknown_features() {
+        let mut ping = Reflection::new_object();
+        ping.set_object_str("version", "1.0.0").unwrap();
+        ping.set_object_str("reply", "pong").unwrap();
+        let mut future = Reflection::new_object();
+        future.set_object_str("version", "1.0.0").unwrap();
+
+        let mock = MockWallet::new("Mock Unknown Features")
+            .add_account(
+                MockAccount::new([7u8; 32]).set_features(&["standard:connect", "mock:ping"]),
+            )
+            .add_custom_feature("mock:ping", ping.take())
+            .add_custom_feature("standard:future", future.take());
+
+        let wallet = Wallet::from_jsvalue(mock.to_jsvalue().unwrap()).unwrap();
+        let features = wallet.features();
+        assert!(wallet.standard_connect());
+        assert_eq!(features.extensions(), &["mock:ping".to_string()]);
+        assert_eq!(
+            features.unknown_features(),
+            &["standard:future".to_string()]
+        );
+        assert_eq!(features.raw_features().len(), 2);
+        assert_eq!(
+            features
+                .raw_feature("standard:future")
+                .and_then(|feature| feature.version())
+                .map(|version| version.to_string()),
+            Some("1.0.0".to_string())
+        );
+        assert_eq!(features.get::<MockPing>().unwrap().unwrap().0, "pong");
+    }
+
+    fn mock_with_ping(name: &str, reply: Option<&str>) -> MockWallet {
+        let mut ping = Reflection::new_object();
+        if let Some(reply) = reply {
+            ping.set_object_str("reply", reply).unwrap();
+        }
+
+        MockWallet::new(name)
+            .add_account(
+                MockAccount::new([7u8; 32]).set_features(&["standard:connect", "mock:ping"]),
+            )
+            .add_custom_feature("mock:ping", ping.take())
+    }
+
+    #[wasm_bindgen_test]
+    async fn parses_registered_features() {
+        let (_, mut adapter) = registered(mock_with_ping("Mock Parsed Before", Some("pong")));
+        adapter.connect_by_name("Mock Parsed Before").await.unwrap();
+        adapter.register_feature_parser::<MockPing>().await;
+
+        // The connected wallet is parsed again too
+        assert_eq!(
+            adapter
+                .connection_info()
+                .await
+                .connected_wallet()
+                .unwrap()
+                .features()
+                .parsed::<MockPing>()
+                .unwrap()
+                .unwrap()
+                .0,
+            "pong"
+        );
+
+        mock_with_ping("Mock Parsed After", Some("pong"))
+            .register(adapter.window())
+            .unwrap();
+        mock_with_ping("Mock Parsed Invalid", None)
+            .register(adapter.window())
+            .unwrap();
+        mock_with_account("Mock Parsed Missing")
+            .register(adapter.window())
+            .unwrap();
+
+        ["Mock Parsed Before", "Mock Parsed After"]
+            .into_iter()
+            .for_each(|name| {
+                let wallet = adapter.get_wallet(name).unwrap();
+                assert_eq!(
+                    wallet.features().parsed::<MockPing>().unwrap().unwrap().0,
+                    "pong"
+                );
+            });
+        assert!(adapter
+            .get_wallet("Mock Parsed Invalid")
+            .unwrap()
+            .features()
+            .parsed::<MockPing>()
+            .unwrap()
+            .is_err());
+        assert!(adapter
+            .get_wallet("Mock Parsed Missing")
+            .unwrap()
+            .features()
+            .parsed::<MockPing>()
+            .is_none());
+        assert!(adapter
+            .storage()
+            .clone_inner()
+            .borrow()
+            .feature_parsers()
+            .is_registered::<MockPing>());
+    }
+
+    struct MockAdd(u32);
+
+    impl ExtensionMethod for MockAdd {
+        const FEATURE: &'static str = "mock:math";
+        const METHOD: &'static str = "add";
+
+        type Output = u32;
+
+        fn to_input(&self) -> WalletResult<JsValue> {
+            Ok(self.0.into())
+        }
+
+        fn from_output(output: JsValue) -> WalletResult<Self::Output> {
+            output
+                .as_f64()
+                .map(|value| value as u32)
+                .ok_or(WalletError::Op("Expected a number".to_string()))
+        }
+    }
+
+    #[wasm_bindgen_test]
+    async fn calls_extension_methods() {
+        let add = Closure::wrap(Box::new(|input: JsValue| {
+            let sum = input.as_f64().unwrap_or_default() + 40.0;
+
+            Promise::resolve(&JsValue::from(sum))
+        }) as Box<dyn Fn(JsValue) -> Promise>);
+        let mut math = Reflection::new_object();
+        math.set_object(&"add".into(), &add.into_js_value())
+            .unwrap();
+
+        let wallet = Wallet::from_jsvalue(
+            mock_with_account("Mock Extension")
+                .add_custom_feature("mock:math", math.take())
+                .to_jsvalue()
+                .unwrap(),
+        )
+        .unwrap();
+
+        let output = wallet
+            .call_extension("mock:math", "add", JsValue::from(1u32))
+            .await
+            .unwrap();
+        assert_eq!(output.as_f64(), Some(41.0));
+        assert_eq!(wallet.call_extension_method(&MockAdd(2)).await, Ok(42));
+        assert_eq!(
+            wallet
+                .call_extension("mock:math", "subtract", JsValue::NULL)
+                .await,
+            Err(WalletError::MissingExtensionMethod {
+                feature: "mock:math".to_string(),
+                method: "subtract".to_string(),
+            })
+        );
+        assert_eq!(
+            wallet
+                .call_extension("mock:unknown", "add", JsValue::NULL)
+                .await,
+            Err(WalletError::MissingExtensionFeature(
+                "mock:unknown".to_string()
+            ))
+        );
+    }
+}
@@ -139,3 +139,88 @@ impl Default for SignedMessageOutput<'_> {
         }
     }
 }
+
+#[cfg(test)]
+#[cfg(feature = "mock")]
+#[cfg(target_arch = "wasm32")]
+mod sign_message_tests {
+    use wasm_bindgen_test::*;
+    use web_sys::{js_sys::Uint8Array, wasm_bindgen::JsValue};
+
+    use crate::{
+        mock::test_utils::{mock_with_account, registered},
+        MockFeature, MockOutcome, Reflection, WalletError,
+    };
+
+    #[wasm_bindgen_test]
+    async fn sign_message_approved() {
+        let (mock, mut adapter) = registered(mock_with_account("Mock Sign Message"));
+        adapter.connect_by_name(mock.name()).await.unwrap();
+
+        let output = adapter.sign_message(b"Hello Solana").await.unwrap();
+
+        assert_eq!(output.message(), "Hello Solana");
+        assert_eq!(
+            output.signature().to_bytes(),
+            mock.accounts()[0].sign(b"Hello Solana")
+        );
+    }
+
+    #[wasm_bindgen_test]
+    async fn sign_message_mismatched_response() {
+        let (mock, mut adapter) = registered(mock_with_account("Mock Sign Message Mismatch"));
+        adapter.connect_by_name(mock.name()).await.unwrap();
+
+        let signed = signed_message_output(b"Tampered", &mock.accounts()[0].sign(b"Tampered"));
+        mock.queue_outcome(MockFeature::SignMessage, MockOutcome::Resolve(signed));
+
+        assert_eq!(
+            adapter.sign_message(b"Hello Solana").await,
+            Err(WalletError::SignedMessageMismatch)
+        );
+    }
+
+    #[wasm_bindgen_test]
+    async fn sign_message_invalid_signature() {
+        let (mock, mut adapter) = registered(mock_with_account("Mock Sign Message Signature"));
+        adapter.connect_by_name(mock.name()).await.unwrap();
+
+        let signed = signed_message_output(b"Hello Solana", &mock.accounts()[0].sign(b"Other"));
+        mock.queue_outcome(MockFeature::SignMessage, MockOutcome::Resolve(signed));
+
+        assert_eq!(
+            adapter.sign_message(b"Hello Solana").await,
+            Err(WalletError::InvalidSignature)
+        );
+    }
+
+    #[wasm_bindgen_test]
+    async fn sign_message_rejected() {
+        let (mock, mut adapter) = registered(mock_with_account("Mock Sign Message Rejected"));
+        adapter.connect_by_name(mock.name()).await.unwrap();
+        mock.queue_outcome(
+            MockFeature::SignMessage,
+            MockOutcome::reject("WalletSignMessageError", "Rejected"),
+        );
+
+        let outcome = adapter.sign_message(b"Hello Solana").await;
+
+        assert!(matches!(
+            outcome,
+            Err(WalletError::JsError { name, message, .. })
+                if name == "WalletSignMessageError" && message == "Rejected"
+        ));
+    }
+
+    fn signed_message_output(message: &[u8], signature: &[u8; 64]) -> JsValue {
+        let mut output = Reflection::new_object();
+        output
+            .set_object(&"signedMessage".into(), &Uint8Array::from(message))
+            .unwrap();
+        output
+            .set_object(&"signature".into(), &Uint8Array::from(signature.as_slice()))
+            .unwrap();
+
+        web_sys::js_sys::Array::of1(output.get_inner()).into()
+    }
+}
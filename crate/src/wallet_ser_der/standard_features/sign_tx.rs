@@ -1,8 +1,12 @@
+use std::collections::BTreeSet;
+
 use ed25519_dalek::Signature;
-use wallet_adapter_common::{clusters::Cluster, WalletCommonUtils};
+use wallet_adapter_common::{
+    clusters::Cluster, transaction::Transaction, TransactionVersion, WalletCommonUtils,
+};
 use web_sys::{
     js_sys::{self, Function},
-    wasm_bindgen::JsValue,
+    wasm_bindgen::{JsCast, JsValue},
 };
 
 use core::hash::Hash;
@@ -10,16 +14,18 @@ use core::hash::Hash;
 use crate::{Commitment, Reflection, SemverVersion, WalletAccount, WalletError, WalletResult};
 
 /// Used in `solana:SignTransaction` and `solana:SignAndSendTransaction`.
-#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct SignTransaction {
     /// The [semver version](SemverVersion) of the
     /// callback function supported by the wallet
     pub version: SemverVersion,
-    /// Whether the wallet supports signing legacy transactions.
-    /// If a wallet does not support this an error is returned
+    /// Whether the wallet supports signing legacy transactions
     pub legacy: bool,
     /// Whether the wallet supports signing versioned transactions
     pub version_zero: bool,
+    /// All the transaction versions the wallet supports.
+    /// Versions unknown to this library are skipped
+    pub supported_versions: BTreeSet<TransactionVersion>,
     // Internally called. Can be either `solana:signTransaction`
     // or `solana:signAndSendTransaction` callback function
     callback: Function,
@@ -36,13 +42,14 @@ impl SignTransaction {
                 WalletError::InternalError(format!("Namespace[`solana:{key}`]: {error}"))
             })?;
 
-        let (legacy, version_zero) = Self::get_tx_version_support(reflection)?;
+        let supported_versions = Self::get_tx_version_support(reflection)?;
 
         Ok(Self {
             version,
             callback,
-            legacy,
-            version_zero,
+            legacy: supported_versions.contains(&TransactionVersion::Legacy),
+            version_zero: supported_versions.contains(&TransactionVersion::V0),
+            supported_versions,
         })
     }
 
@@ -62,7 +69,17 @@ impl SignTransaction {
         Self::new(reflection, version, "signAndSendTransaction")
     }
 
-    fn get_tx_version_support(inner_value: &Reflection) -> WalletResult<(bool, bool)> {
+    /// Parse a `solana:signAndSendAllTransactions` callback from the [JsValue]
+    pub(crate) fn new_sign_and_send_all_tx(
+        reflection: &Reflection,
+        version: SemverVersion,
+    ) -> WalletResult<Self> {
+        Self::new(reflection, version, "signAndSendAllTransactions")
+    }
+
+    fn get_tx_version_support(
+        inner_value: &Reflection,
+    ) -> WalletResult<BTreeSet<TransactionVersion>> {
         let tx_version_support_jsvalue = inner_value
             .reflect_inner("supportedTransactionVersions")
             .or(Err(WalletError::ExpectedValueNotFound(
@@ -70,26 +87,33 @@ impl SignTransaction {
             )))?;
         let tx_version_support = Reflection::new(tx_version_support_jsvalue)?.into_array()?;
 
-        let mut legacy = false;
-        let mut version_zero = false;
+        Ok(tx_version_support
+            .iter()
+            .filter_map(|value| {
+                if let Some(version) = value.as_string() {
+                    TransactionVersion::parse(&version)
+                } else {
+                    value
+                        .as_f64()
+                        .filter(|version| version.fract() == 0.0 && *version >= 0.0)
+                        .and_then(|version| u8::try_from(version as u64).ok())
+                        .and_then(TransactionVersion::from_number)
+                }
+            })
+            .collect())
+    }
 
-        tx_version_support.iter().try_for_each(|value| {
-            if value == JsValue::from_str("legacy") {
-                legacy = true;
-            } else if value == 0 {
-                version_zero = true;
-            } else {
-                return Err(WalletError::UnsupportedTransactionVersion);
-            }
+    /// Check that the wallet supports the version read from the message prefix of the transaction
+    pub fn check_transaction_version(&self, transaction_bytes: &[u8]) -> WalletResult<()> {
+        let version = TransactionVersion::from_transaction_bytes(transaction_bytes)?;
 
+        if self.supported_versions.contains(&version) {
             Ok(())
-        })?;
-
-        if !legacy {
-            return Err(WalletError::LegacyTransactionSupportRequired);
+        } else {
+            Err(WalletError::TransactionVersionNotSupported(
+                version.to_string(),
+            ))
         }
-
-        Ok((legacy, version_zero))
     }
 
     pub(crate) async fn call_sign_tx(
@@ -98,21 +122,101 @@ impl SignTransaction {
         transaction_bytes: &[u8],
         cluster: Option<Cluster>,
     ) -> WalletResult<Vec<Vec<u8>>> {
-        let tx_bytes_value: js_sys::Uint8Array = transaction_bytes.into();
+        self.call_sign_all_tx(wallet_account, &[transaction_bytes], cluster)
+            .await
+    }
 
-        let mut tx_object = Reflection::new_object();
-        tx_object.set_object(&"account".into(), &wallet_account.js_value)?;
-        tx_object.set_object(&"transaction".into(), &tx_bytes_value)?;
-        if let Some(cluster) = cluster {
-            tx_object.set_object(&"chain".into(), &cluster.chain().into())?;
+    /// Send all the transactions to the wallet in a single `solana:signTransaction` request.
+    /// The callback is variadic so each transaction is passed as a separate argument.
+    /// The wallet is not called if there are no transactions
+    pub(crate) async fn call_sign_all_tx(
+        &self,
+        wallet_account: &WalletAccount,
+        transactions: &[&[u8]],
+        cluster: Option<Cluster>,
+    ) -> WalletResult<Vec<Vec<u8>>> {
+        if transactions.is_empty() {
+            return Ok(Vec::new());
         }
 
-        let outcome = self.callback.call1(&JsValue::null(), &tx_object.take())?;
+        transactions
+            .iter()
+            .try_for_each(|transaction_bytes| self.check_transaction_version(transaction_bytes))?;
+
+        let inputs = transactions
+            .iter()
+            .map(|transaction_bytes| {
+                let tx_bytes_value: js_sys::Uint8Array = (*transaction_bytes).into();
+
+                let mut tx_object = Reflection::new_object();
+                tx_object.set_object(&"account".into(), &wallet_account.js_value)?;
+                tx_object.set_object(&"transaction".into(), &tx_bytes_value)?;
+                if let Some(cluster) = cluster.as_ref() {
+                    tx_object.set_object(&"chain".into(), &cluster.chain().into())?;
+                }
+
+                Ok(tx_object.take())
+            })
+            .collect::<WalletResult<js_sys::Array>>()?;
+
+        let outcome = self.callback.apply(&JsValue::null(), &inputs)?;
 
         let outcome = js_sys::Promise::resolve(&outcome);
 
         let success = wasm_bindgen_futures::JsFuture::from(outcome).await?;
-        Reflection::new(success)?.get_bytes_from_vec("signedTransaction")
+        let signed_transactions =
+            Reflection::new(success)?.get_bytes_from_vec("signedTransaction")?;
+
+        if signed_transactions.len() != transactions.len() {
+            return Err(WalletError::SignedTransactionsCountMismatch {
+                expected: transactions.len(),
+                received: signed_transactions.len(),
+            });
+        }
+
+        transactions
+            .iter()
+            .zip(signed_transactions.iter())
+            .try_for_each(|(transaction_bytes, signed_transaction_bytes)| {
+                Self::verify_signed_transaction(
+                    wallet_account,
+                    transaction_bytes,
+                    signed_transaction_bytes,
+                )
+            })?;
+
+        Ok(signed_transactions)
+    }
+
+    /// Check that the wallet only added signatures to the transaction and that
+    /// the signature of the account is valid for the message sent to be signed
+    fn verify_signed_transaction(
+        wallet_account: &WalletAccount,
+        transaction_bytes: &[u8],
+        signed_transaction_bytes: &[u8],
+    ) -> WalletResult<()> {
+        let transaction = Transaction::decode(transaction_bytes)?;
+        let signed_transaction = Transaction::decode(signed_transaction_bytes)?;
+        let message_bytes = Transaction::message_bytes(signed_transaction_bytes)?;
+
+        if message_bytes != Transaction::message_bytes(transaction_bytes)?
+            || signed_transaction.signatures.len() != transaction.signatures.len()
+        {
+            return Err(WalletError::SignedTransactionMismatch);
+        }
+
+        let public_key = &wallet_account.account.public_key;
+
+        let signature = signed_transaction
+            .signers()
+            .find_map(|(signer, signature)| (signer == public_key).then_some(signature))
+            .ok_or(WalletError::AccountNotTransactionSigner(
+                wallet_account.address().to_string(),
+            ))?;
+
+        WalletCommonUtils::verify(public_key, message_bytes, signature).or(Err(
+            WalletError::InvalidTransactionSignature(wallet_account.address().to_string()),
+        ))
     }
 
     pub(crate) async fn call_sign_and_send_transaction(
@@ -122,6 +226,8 @@ impl SignTransaction {
         cluster: Cluster,
         options: SendOptions,
     ) -> WalletResult<Signature> {
+        self.check_transaction_version(transaction_bytes)?;
+
         let tx_bytes_value: js_sys::Uint8Array = transaction_bytes.into();
 
         let mut tx_object = Reflection::new_object();
@@ -145,6 +251,87 @@ impl SignTransaction {
             })
             .ok_or(WalletError::SendAndSignTransactionSignatureEmpty)?
     }
+    /// Send all the transactions to the wallet in a single `solana:signAndSendAllTransactions` request.
+    /// The wallet settles each transaction separately so a result is returned for every transaction
+    pub(crate) async fn call_sign_and_send_all_transactions(
+        &self,
+        wallet_account: &WalletAccount,
+        transactions: &[(&[u8], SendOptions)],
+        cluster: Cluster,
+        mode: SignAndSendTransactionMode,
+    ) -> WalletResult<Vec<WalletResult<Signature>>> {
+        transactions.iter().try_for_each(|(transaction_bytes, _)| {
+            self.check_transaction_version(transaction_bytes)
+        })?;
+
+        let inputs = transactions
+            .iter()
+            .map(|(transaction_bytes, options)| {
+                let tx_bytes_value: js_sys::Uint8Array = (*transaction_bytes).into();
+
+                let mut tx_object = Reflection::new_object();
+                tx_object.set_object(&"account".into(), &wallet_account.js_value)?;
+                tx_object.set_object(&"transaction".into(), &tx_bytes_value)?;
+                tx_object.set_object(&"chain".into(), &cluster.chain().into())?;
+                tx_object.set_object(&"options".into(), &options.to_object()?)?;
+
+                Ok(tx_object.take())
+            })
+            .collect::<WalletResult<js_sys::Array>>()?;
+
+        let mut all_options = Reflection::new_object();
+        all_options.set_object_str("mode", mode.as_str())?;
+
+        let outcome = self
+            .callback
+            .call2(&JsValue::null(), &inputs, &all_options.take())?;
+
+        let outcome = js_sys::Promise::resolve(&outcome);
+
+        let success = wasm_bindgen_futures::JsFuture::from(outcome).await?;
+        let settled_results = Reflection::new(success)?.into_array()?;
+
+        if settled_results.length() as usize != transactions.len() {
+            return Err(WalletError::SignedTransactionsCountMismatch {
+                expected: transactions.len(),
+                received: settled_results.length() as usize,
+            });
+        }
+
+        settled_results
+            .iter()
+            .map(|settled_result| {
+                let settled_result = Reflection::new(settled_result)?;
+
+                Ok(match settled_result.string("status")?.as_str() {
+                    "fulfilled" => Self::parse_signature(settled_result.reflect_inner("value")?),
+                    _ => Err(settled_result
+                        .reflect_inner("reason")
+                        .unwrap_or_default()
+                        .into()),
+                })
+            })
+            .collect()
+    }
+
+    fn parse_signature(output: JsValue) -> WalletResult<Signature> {
+        let signature = Reflection::new(output)?.reflect_bytes("signature")?;
+        let bytes = WalletCommonUtils::to64byte_array(&signature)?;
+
+        Ok(WalletCommonUtils::signature(&bytes))
+    }
+}
+
+impl Default for SignTransaction {
+    fn default() -> Self {
+        Self {
+            version: SemverVersion::default(),
+            legacy: bool::default(),
+            version_zero: bool::default(),
+            supported_versions: BTreeSet::default(),
+            callback: JsValue::undefined().unchecked_into(),
+        }
+    }
 }
 
 #[allow(clippy::non_canonical_partial_ord_impl)]
@@ -166,19 +353,117 @@ impl Hash for SignTransaction {
     }
 }
 
+/// How a wallet sends the transactions of a `solana:signAndSendAllTransactions` request
+#[derive(Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+pub enum SignAndSendTransactionMode {
+    /// All the transactions are sent at the same time
+    #[default]
+    Parallel,
+    /// Each transaction is sent after the previous one is confirmed
+    Serial,
+}
+
+impl SignAndSendTransactionMode {
+    /// Get the mode as a [str] format
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Parallel => "parallel",
+            Self::Serial => "serial",
+        }
+    }
+}
+
+impl TryFrom<&str> for SignAndSendTransactionMode {
+    type Error = WalletError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "parallel" => Ok(Self::Parallel),
+            "serial" => Ok(Self::Serial),
+            _ => Err(WalletError::UnsupportedSendMode(value.to_string())),
+        }
+    }
+}
+
 /// Options used in the `solana:signAndSendTransaction` method
 /// on a [crate::Wallet]. These options are:
 /// - [preflight_commitment](Commitment)
 /// - [skip_preflight](bool)
-/// - [max_retries](u8)
+/// - [max_retries](usize)
+/// - [min_context_slot](u64)
+/// - [commitment](Commitment)
+/// - [mode](SignAndSendTransactionMode)
+///
+/// #### Example
+/// ```rust
+/// use wallet_adapter::{Commitment, SendOptions};
+///
+/// let mut options = SendOptions::new();
+/// options
+///     .set_skip_preflight(true)
+///     .set_max_retries(300)
+///     .set_min_context_slot(325_000_000)
+///     .set_commitment(Commitment::Confirmed);
+/// ```
 #[derive(Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
 pub struct SendOptions {
     preflight_commitment: Commitment,
     skip_preflight: bool,
-    max_retries: u8,
+    max_retries: Option<usize>,
+    min_context_slot: Option<u64>,
+    commitment: Option<Commitment>,
+    mode: Option<SignAndSendTransactionMode>,
 }
 
 impl SendOptions {
+    /// Create the default [SendOptions]. Options that are not set
+    /// are not sent to the wallet so the wallet uses its own defaults
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the commitment level used when simulating the transaction before it is sent
+    pub fn set_preflight_commitment(&mut self, preflight_commitment: Commitment) -> &mut Self {
+        self.preflight_commitment = preflight_commitment;
+
+        self
+    }
+
+    /// Skip simulating the transaction before it is sent
+    pub fn set_skip_preflight(&mut self, skip_preflight: bool) -> &mut Self {
+        self.skip_preflight = skip_preflight;
+
+        self
+    }
+
+    /// Set the maximum number of times the RPC node retries sending the transaction
+    pub fn set_max_retries(&mut self, max_retries: usize) -> &mut Self {
+        self.max_retries.replace(max_retries);
+
+        self
+    }
+
+    /// Set the minimum slot the RPC node must have reached before the transaction is sent
+    pub fn set_min_context_slot(&mut self, min_context_slot: u64) -> &mut Self {
+        self.min_context_slot.replace(min_context_slot);
+
+        self
+    }
+
+    /// Set the commitment level the wallet waits for after sending the transaction
+    pub fn set_commitment(&mut self, commitment: Commitment) -> &mut Self {
+        self.commitment.replace(commitment);
+
+        self
+    }
+
+    /// Set how the wallet sends the transactions when several transactions are sent in one request
+    pub fn set_mode(&mut self, mode: SignAndSendTransactionMode) -> &mut Self {
+        self.mode.replace(mode);
+
+        self
+    }
+
     /// Converts [SendOptions] to a [JsValue] which can be passed
     /// to the browser wallet when making requests.
     /// Internally, it is a [js_sys::Object]
@@ -186,8 +471,462 @@ impl SendOptions {
         let mut reflection = Reflection::new_object();
         reflection.set_object_str("preflightCommitment", self.preflight_commitment.as_str())?;
         reflection.set_object(&"skipPreflight".into(), &JsValue::from(self.skip_preflight))?;
-        reflection.set_object(&"maxRetries".into(), &JsValue::from(self.max_retries))?;
+        if let Some(max_retries) = self.max_retries {
+            reflection.set_object(&"maxRetries".into(), &JsValue::from(max_retries))?;
+        }
+        if let Some(min_context_slot) = self.min_context_slot {
+            // JavaScript numbers are used since `JsValue::from(u64)` is a `BigInt`
+            reflection.set_object(
+                &"minContextSlot".into(),
+                &JsValue::from_f64(min_context_slot as f64),
+            )?;
+        }
+        if let Some(commitment) = self.commitment {
+            reflection.set_object_str("commitment", commitment.as_str())?;
+        }
+        if let Some(mode) = self.mode {
+            reflection.set_object_str("mode", mode.as_str())?;
+        }
 
         Ok(reflection.take())
     }
+
+    /// Parses the `options` object an app passes to `solana:signAndSendTransaction`.
+    /// Missing fields fall back to their default values
+    #[cfg(feature = "provider")]
+    pub(crate) fn from_object(options: &JsValue) -> WalletResult<Self> {
+        let mut send_options = Self::default();
+
+        if Reflection::check_is_undefined(options).is_err() {
+            return Ok(send_options);
+        }
+
+        let options = Reflection::new(options.clone())?;
+
+        if let Some(commitment) = options.string_optional("preflightCommitment")? {
+            send_options.preflight_commitment = commitment.as_str().try_into()?;
+        }
+        if let Ok(skip_preflight) = options.reflect_inner("skipPreflight") {
+            send_options.skip_preflight = skip_preflight.as_bool().unwrap_or_default();
+        }
+        send_options.max_retries =
+            Self::number_optional(&options, "maxRetries").map(|max_retries| max_retries as usize);
+        send_options.min_context_slot = Self::number_optional(&options, "minContextSlot")
+            .map(|min_context_slot| min_context_slot as u64);
+        if let Some(commitment) = options.string_optional("commitment")? {
+            send_options
+                .commitment
+                .replace(commitment.as_str().try_into()?);
+        }
+        if let Some(mode) = options.string_optional("mode")? {
+            send_options.mode.replace(mode.as_str().try_into()?);
+        }
+
+        Ok(send_options)
+    }
+
+    #[cfg(feature = "provider")]
+    fn number_optional(options: &Reflection, key: &str) -> Option<f64> {
+        options
+            .reflect_inner(key)
+            .ok()
+            .and_then(|value| value.as_f64())
+    }
+
+    /// The commitment level used when simulating the transaction before it is sent
+    pub fn preflight_commitment(&self) -> Commitment {
+        self.preflight_commitment
+    }
+
+    /// Whether the transaction simulation is skipped
+    pub fn skip_preflight(&self) -> bool {
+        self.skip_preflight
+    }
+
+    /// The maximum number of times the RPC node retries sending the transaction
+    pub fn max_retries(&self) -> Option<usize> {
+        self.max_retries
+    }
+
+    /// The minimum slot the RPC node must have reached before the transaction is sent
+    pub fn min_context_slot(&self) -> Option<u64> {
+        self.min_context_slot
+    }
+
+    /// The commitment level the wallet waits for after sending the transaction
+    pub fn commitment(&self) -> Option<Commitment> {
+        self.commitment
+    }
+
+    /// How the wallet sends the transactions when several transactions are sent in one request
+    pub fn mode(&self) -> Option<SignAndSendTransactionMode> {
+        self.mode
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "mock")]
+#[cfg(target_arch = "wasm32")]
+mod sign_tx_tests {
+    use wallet_adapter_common::transaction::{
+        CompiledInstruction, Message, MessageHeader, Transaction,
+    };
+    use wasm_bindgen_test::*;
+    use web_sys::{js_sys::Uint8Array, wasm_bindgen::JsValue};
+
+    use crate::{
+        mock::test_utils::{mock_with_account, registered},
+        Cluster, Commitment, CustomCluster, MockFeature, MockOutcome, MockWallet, Reflection,
+        SendOptions, SignAndSendTransactionMode, TransactionVersion, WalletError,
+    };
+
+    fn unsigned_transaction(fee_payer: [u8; 32], version: TransactionVersion, data: u8) -> Vec<u8> {
+        Transaction {
+            signatures: vec![[0u8; 64]],
+            message: Message {
+                version,
+                header: MessageHeader {
+                    num_required_signatures: 1,
+                    num_readonly_signed_accounts: 0,
+                    num_readonly_unsigned_accounts: 1,
+                },
+                account_keys: vec![fee_payer, [0u8; 32]],
+                recent_blockhash: [data; 32],
+                instructions: vec![CompiledInstruction {
+                    program_id_index: 1,
+                    accounts: vec![0],
+                    data: vec![data; 12],
+                }],
+                address_table_lookups: Vec::default(),
+            },
+        }
+        .encode()
+        .unwrap()
+    }
+
+    fn resolve_signed_transaction(mock: &MockWallet, signed_transaction: &[u8]) {
+        let mut output = Reflection::new_object();
+        output
+            .set_object(
+                &"signedTransaction".into(),
+                &Uint8Array::from(signed_transaction),
+            )
+            .unwrap();
+        mock.queue_outcome(
+            MockFeature::SignTransaction,
+            MockOutcome::resolve(web_sys::js_sys::Array::of1(output.get_inner())),
+        );
+    }
+
+    #[wasm_bindgen_test]
+    async fn sign_transaction_approved() {
+        let (mock, mut adapter) = registered(mock_with_account("Mock Sign Transaction"));
+        adapter.connect_by_name(mock.name()).await.unwrap();
+
+        let account = &mock.accounts()[0];
+        let transaction = unsigned_transaction(account.public_key(), TransactionVersion::Legacy, 1);
+        let signed = adapter.sign_transaction(&transaction, None).await.unwrap();
+
+        let message_bytes = Transaction::message_bytes(&transaction).unwrap();
+        let signed = Transaction::decode(&signed[0]).unwrap();
+        assert_eq!(
+            signed.message,
+            Transaction::decode(&transaction).unwrap().message
+        );
+        assert_eq!(signed.signatures, vec![account.sign(message_bytes)]);
+    }
+
+    #[wasm_bindgen_test]
+    async fn sign_transaction_verifies_output() {
+        let (mock, mut adapter) = registered(mock_with_account("Mock Verify Signed Transaction"));
+        adapter.connect_by_name(mock.name()).await.unwrap();
+
+        let account = &mock.accounts()[0];
+        let transaction = unsigned_transaction(account.public_key(), TransactionVersion::Legacy, 1);
+
+        let mut tampered = Transaction::decode(&transaction).unwrap();
+        tampered.message.instructions[0].data = vec![255u8; 12];
+        resolve_signed_transaction(&mock, &tampered.encode().unwrap());
+        assert_eq!(
+            adapter.sign_transaction(&transaction, None).await,
+            Err(WalletError::SignedTransactionMismatch)
+        );
+
+        resolve_signed_transaction(&mock, &transaction);
+        assert_eq!(
+            adapter.sign_transaction(&transaction, None).await,
+            Err(WalletError::InvalidTransactionSignature(account.address()))
+        );
+
+        let other_signer = unsigned_transaction([5u8; 32], TransactionVersion::Legacy, 1);
+        resolve_signed_transaction(&mock, &other_signer);
+        assert_eq!(
+            adapter.sign_transaction(&other_signer, None).await,
+            Err(WalletError::AccountNotTransactionSigner(account.address()))
+        );
+    }
+
+    #[wasm_bindgen_test]
+    async fn version_zero_only_wallet() {
+        let mock =
+            mock_with_account("Mock Version Zero Only").set_supported_transaction_versions(&["0"]);
+        let (mock, mut adapter) = registered(mock);
+        adapter.connect_by_name(mock.name()).await.unwrap();
+
+        let wallet = adapter.get_wallet(mock.name()).unwrap();
+        assert!(wallet
+            .sign_transaction_versions()
+            .contains(&TransactionVersion::V0));
+        assert!(!wallet
+            .sign_transaction_versions()
+            .contains(&TransactionVersion::Legacy));
+
+        assert_eq!(
+            adapter.sign_transaction(&[1u8; 128], None).await,
+            Err(WalletError::TransactionVersionNotSupported(
+                "legacy".to_string()
+            ))
+        );
+        assert!(mock.calls_for(MockFeature::SignTransaction).is_empty());
+
+        let version_zero =
+            unsigned_transaction(mock.accounts()[0].public_key(), TransactionVersion::V0, 1);
+        let signed = adapter.sign_transaction(&version_zero, None).await.unwrap();
+        assert_eq!(
+            Transaction::message_bytes(&signed[0]),
+            Transaction::message_bytes(&version_zero)
+        );
+    }
+
+    #[wasm_bindgen_test]
+    async fn sign_all_transactions_in_one_request() {
+        let (mock, mut adapter) = registered(mock_with_account("Mock Sign All Transactions"));
+        adapter.connect_by_name(mock.name()).await.unwrap();
+
+        let transactions = (1..=3)
+            .map(|data| {
+                unsigned_transaction(
+                    mock.accounts()[0].public_key(),
+                    TransactionVersion::Legacy,
+                    data,
+                )
+            })
+            .collect::<Vec<Vec<u8>>>();
+        let signed = adapter
+            .sign_all_transactions(
+                &transactions.iter().map(Vec::as_slice).collect::<Vec<_>>(),
+                None,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(signed.len(), 3);
+        signed
+            .iter()
+            .zip(transactions.iter())
+            .for_each(|(signed, transaction)| {
+                assert_eq!(
+                    Transaction::message_bytes(signed),
+                    Transaction::message_bytes(transaction)
+                );
+            });
+
+        let calls = mock.calls_for(MockFeature::SignTransaction);
+        assert_eq!(calls.len(), 1);
+        assert_eq!(calls[0].inputs.len(), 3);
+
+        assert_eq!(
+            adapter.sign_all_transactions(&[], None).await,
+            Ok(Vec::new())
+        );
+        assert_eq!(mock.calls_for(MockFeature::SignTransaction).len(), 1);
+    }
+
+    #[wasm_bindgen_test]
+    async fn sign_all_transactions_count_mismatch() {
+        let (mock, mut adapter) = registered(mock_with_account("Mock Sign All Mismatch"));
+        adapter.connect_by_name(mock.name()).await.unwrap();
+
+        let mut output = Reflection::new_object();
+        output
+            .set_object(
+                &"signedTransaction".into(),
+                &Uint8Array::from(&[1u8; 256][..]),
+            )
+            .unwrap();
+        mock.queue_outcome(
+            MockFeature::SignTransaction,
+            MockOutcome::resolve(web_sys::js_sys::Array::of1(output.get_inner())),
+        );
+
+        assert_eq!(
+            adapter
+                .sign_all_transactions(&[&[1u8; 256], &[2u8; 256]], None)
+                .await,
+            Err(WalletError::SignedTransactionsCountMismatch {
+                expected: 2,
+                received: 1
+            })
+        );
+    }
+
+    #[wasm_bindgen_test]
+    async fn sign_and_send_transaction_approved() {
+        let (mock, mut adapter) = registered(mock_with_account("Mock Sign And Send"));
+        adapter.connect_by_name(mock.name()).await.unwrap();
+
+        let signature = adapter
+            .sign_and_send_transaction(&[1u8; 128], Default::default(), Default::default())
+            .await
+            .unwrap();
+
+        assert_eq!(signature.to_bytes(), mock.accounts()[0].sign(&[1u8; 128]));
+    }
+
+    #[wasm_bindgen_test]
+    async fn sign_and_send_transaction_options() {
+        let (mock, mut adapter) = registered(mock_with_account("Mock Send Options"));
+        adapter.connect_by_name(mock.name()).await.unwrap();
+
+        let mut options = SendOptions::new();
+        options
+            .set_skip_preflight(true)
+            .set_max_retries(300)
+            .set_min_context_slot(325_000_000)
+            .set_commitment(Commitment::Confirmed);
+
+        adapter
+            .sign_and_send_transaction(&[1u8; 128], Cluster::DevNet, options)
+            .await
+            .unwrap();
+
+        let input = Reflection::new(
+            mock.calls_for(MockFeature::SignAndSendTransaction)[0]
+                .input
+                .clone(),
+        )
+        .unwrap();
+        let sent_options = Reflection::new(input.reflect_inner("options").unwrap()).unwrap();
+
+        assert_eq!(
+            sent_options.reflect_inner("skipPreflight").unwrap(),
+            JsValue::TRUE
+        );
+        assert_eq!(
+            sent_options.reflect_inner("maxRetries").unwrap().as_f64(),
+            Some(300.0)
+        );
+        assert_eq!(
+            sent_options
+                .reflect_inner("minContextSlot")
+                .unwrap()
+                .as_f64(),
+            Some(325_000_000.0)
+        );
+        assert_eq!(sent_options.string("commitment").unwrap(), "confirmed");
+        assert!(sent_options.reflect_inner("mode").is_err());
+    }
+
+    #[wasm_bindgen_test]
+    async fn custom_cluster_uses_genesis_chain() {
+        let (mock, mut adapter) = registered(mock_with_account("Mock Custom Cluster"));
+        adapter.connect_by_name(mock.name()).await.unwrap();
+
+        let mut custom =
+            CustomCluster::new("Private RPC", "https://rpc.example.com", "solana:mainnet").unwrap();
+        custom.set_ws_endpoint("wss://rpc.example.com");
+        adapter.add_cluster(custom.clone()).add_cluster(
+            CustomCluster::new("Other RPC", "https://other.example.com", "devnet").unwrap(),
+        );
+
+        let clusters = adapter.clusters().await.unwrap();
+        assert_eq!(clusters.len(), 6);
+        assert!(clusters.contains(&Cluster::Custom(custom.clone())));
+
+        adapter
+            .sign_and_send_transaction(&[1u8; 128], custom.into(), SendOptions::new())
+            .await
+            .unwrap();
+
+        let input = Reflection::new(
+            mock.calls_for(MockFeature::SignAndSendTransaction)[0]
+                .input
+                .clone(),
+        )
+        .unwrap();
+        assert_eq!(input.string("chain").unwrap(), "solana:mainnet");
+    }
+
+    #[wasm_bindgen_test]
+    async fn sign_and_send_all_transactions_settled() {
+        let (mock, mut adapter) = registered(mock_with_account("Mock Sign And Send All"));
+        adapter.connect_by_name(mock.name()).await.unwrap();
+
+        let transactions: [(&[u8], SendOptions); 2] = [
+            (&[1u8; 256], SendOptions::default()),
+            (&[2u8; 256], SendOptions::default()),
+        ];
+
+        let results = adapter
+            .sign_and_send_all_transactions(
+                &transactions,
+                Cluster::DevNet,
+                SignAndSendTransactionMode::Serial,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            results[0].as_ref().unwrap().to_bytes(),
+            mock.accounts()[0].sign(&[1u8; 256])
+        );
+        assert_eq!(
+            results[1].as_ref().unwrap().to_bytes(),
+            mock.accounts()[0].sign(&[2u8; 256])
+        );
+
+        let call = &mock.calls_for(MockFeature::SignAndSendAllTransactions)[0];
+        let mode = Reflection::new(call.inputs[1].clone())
+            .unwrap()
+            .string("mode")
+            .unwrap();
+        assert_eq!(mode, "serial");
+
+        let mut fulfilled_value = Reflection::new_object();
+        fulfilled_value
+            .set_object(&"signature".into(), &Uint8Array::from(&[5u8; 64][..]))
+            .unwrap();
+        let mut fulfilled = Reflection::new_object();
+        fulfilled.set_object_str("status", "fulfilled").unwrap();
+        fulfilled
+            .set_object(&"value".into(), fulfilled_value.get_inner())
+            .unwrap();
+        let mut rejected = Reflection::new_object();
+        rejected.set_object_str("status", "rejected").unwrap();
+        rejected
+            .set_object(
+                &"reason".into(),
+                &MockOutcome::js_error("SendError", "Blockhash not found"),
+            )
+            .unwrap();
+        mock.queue_outcome(
+            MockFeature::SignAndSendAllTransactions,
+            MockOutcome::resolve(web_sys::js_sys::Array::of2(
+                fulfilled.get_inner(),
+                rejected.get_inner(),
+            )),
+        );
+
+        let results = adapter
+            .sign_and_send_all_transactions(&transactions, Cluster::DevNet, Default::default())
+            .await
+            .unwrap();
+
+        assert_eq!(results[0].as_ref().unwrap().to_bytes(), [5u8; 64]);
+        assert!(matches!(
+            &results[1],
+            Err(WalletError::JsError { name, message, .. })
+                if name == "SendError" && message == "Blockhash not found"
+        ));
+    }
 }
@@ -65,3 +65,53 @@ impl SignIn {
         })
     }
 }
+
+#[cfg(test)]
+#[cfg(feature = "mock")]
+#[cfg(target_arch = "wasm32")]
+mod signin_tests {
+    use wasm_bindgen_test::*;
+
+    use crate::{
+        mock::test_utils::{mock_with_account, registered},
+        MockFeature, SigninInput, WalletError,
+    };
+
+    #[wasm_bindgen_test]
+    async fn sign_in_approved() {
+        let (mock, mut adapter) = registered(mock_with_account("Mock Sign In"));
+        adapter.connect_by_name(mock.name()).await.unwrap();
+
+        let address = mock.accounts()[0].address();
+        let mut signin_input = SigninInput::new();
+        signin_input
+            .set_custom_domain("localhost")
+            .set_statement("Login To Dev")
+            .set_chain_id(Default::default())
+            .set_nonce()
+            .set_address(&address)
+            .unwrap();
+
+        let output = adapter
+            .sign_in(&signin_input, mock.accounts()[0].public_key())
+            .await
+            .unwrap();
+
+        assert_eq!(output.address(), address);
+    }
+
+    #[wasm_bindgen_test]
+    async fn sign_in_missing_feature() {
+        let (mock, mut adapter) = registered(
+            mock_with_account("Mock Sign In Missing").remove_feature(MockFeature::SignIn),
+        );
+        adapter.connect_by_name(mock.name()).await.unwrap();
+
+        assert_eq!(
+            adapter
+                .sign_in(&SigninInput::new(), mock.accounts()[0].public_key())
+                .await,
+            Err(WalletError::MissingSignInFunction)
+        );
+    }
+}
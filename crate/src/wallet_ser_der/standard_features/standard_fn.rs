@@ -1,13 +1,16 @@
 use core::hash::Hash;
 
-use web_sys::js_sys::Function;
+use web_sys::{
+    js_sys::Function,
+    wasm_bindgen::{JsCast, JsValue},
+};
 
 use crate::{Reflection, SemverVersion, WalletError, WalletResult};
 
 /// A struct containing the [semver version](SemverVersion)
 /// and [callback function](Function) within the `standard:` namespace as
 /// defined by the wallet standard
-#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct StandardFunction {
     pub(crate) version: SemverVersion,
     pub(crate) callback: Function,
@@ -41,6 +44,15 @@ impl StandardFunction {
     }
 }
 
+impl Default for StandardFunction {
+    fn default() -> Self {
+        Self {
+            version: SemverVersion::default(),
+            callback: JsValue::undefined().unchecked_into(),
+        }
+    }
+}
+
 #[allow(clippy::non_canonical_partial_ord_impl)]
 impl PartialOrd for StandardFunction {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
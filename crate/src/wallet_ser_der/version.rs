@@ -2,27 +2,44 @@ use std::borrow::Cow;
 
 use crate::{Reflection, WalletError, WalletResult};
 
-use wallet_adapter_common::SemverVersion as SemverVersionData;
+use wallet_adapter_common::{
+    PreReleaseIdentifier, SemverRequirement, SemverVersion as SemverVersionData,
+};
 /// Semver Versioning struct
 #[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct SemverVersion(pub SemverVersionData);
 
 impl SemverVersion {
     /// The major version
-    pub fn major(&self) -> u8 {
+    pub fn major(&self) -> u64 {
         self.0.major()
     }
 
     /// The minor version
-    pub fn minor(&self) -> u8 {
+    pub fn minor(&self) -> u64 {
         self.0.minor()
     }
 
     /// The patch version
-    pub fn patch(&self) -> u8 {
+    pub fn patch(&self) -> u64 {
         self.0.patch()
     }
 
+    /// The pre-release identifiers, empty for a release version
+    pub fn pre_release(&self) -> &[PreReleaseIdentifier] {
+        self.0.pre_release()
+    }
+
+    /// The build metadata identifiers
+    pub fn build(&self) -> &[String] {
+        self.0.build()
+    }
+
+    /// Check whether the version satisfies the requirement
+    pub fn satisfies(&self, requirement: &SemverRequirement) -> bool {
+        self.0.satisfies(requirement)
+    }
+
     /// Parse the version from a [web_sys::wasm_bindgen::JsValue]
     pub(crate) fn from_jsvalue(reflection: &Reflection) -> WalletResult<Self> {
         let version = reflection
@@ -36,29 +53,9 @@ impl SemverVersion {
         SemverVersion::parse(&version)
     }
 
-    /// Parse a semver versioned string  into [Self]
+    /// Parse a semver versioned string into [Self]
     pub fn parse(version: &str) -> WalletResult<Self> {
-        let chunks = version.split(".").collect::<Vec<&str>>();
-
-        if chunks.len() != 3 {
-            return Err(WalletError::InvalidWalletVersion(version.to_string()));
-        }
-
-        let version_chunks = chunks
-            .iter()
-            .map(|chunk| {
-                chunk
-                    .parse::<u8>()
-                    .map_err(|_| WalletError::InvalidSemVerNumber(chunk.to_string()))
-            })
-            .collect::<WalletResult<Vec<u8>>>()?;
-
-        Ok(Self(
-            SemverVersionData::new()
-                .set_major(version_chunks[0])
-                .set_minor(version_chunks[1])
-                .set_patch(version_chunks[2]),
-        ))
+        Ok(Self(SemverVersionData::parse(version)?))
     }
 
     /// Get the string version of [Self] in the format `major.minor.patch`
@@ -1,21 +1,24 @@
-use std::borrow::Cow;
+use std::{borrow::Cow, collections::BTreeSet};
 
-use async_channel::Receiver;
 use ed25519_dalek::Signature;
 use wallet_adapter_common::{
-    chains::ChainSupport, clusters::Cluster, signin_standard::SignInOutput, WalletData,
+    chains::{ChainId, ChainSupport},
+    clusters::Cluster,
+    signin_standard::SignInOutput,
+    TransactionVersion, WalletData,
 };
 use web_sys::wasm_bindgen::JsValue;
 
 use crate::{
-    ConnectionInfoInner, Features, Reflection, SemverVersion, SigninInput, WalletAccount,
-    WalletError, WalletEventSender, WalletIcon, WalletResult,
+    ConnectionInfoInner, EventsSubscription, ExtensionMethod, Features, Reflection, SemverVersion,
+    SignAndSendTransactionMode, SigninInput, WalletAccount, WalletError, WalletEventSender,
+    WalletIcon, WalletResult, WalletStorage,
 };
 
 use super::{SendOptions, SignedMessageOutput};
 
 /// A wallet implementing wallet standard
-#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Wallet {
     pub(crate) data: WalletData,
     pub(crate) accounts: Vec<WalletAccount>,
@@ -25,7 +28,11 @@ pub struct Wallet {
 impl Wallet {
     /// Send a request to connect to a browser wallet
     pub async fn connect(&self) -> WalletResult<WalletAccount> {
-        self.features.connect.call_connect().await
+        self.features
+            .connect
+            .call_connect(false)
+            .await
+            .map(|mut accounts| accounts.remove(0))
     }
 
     /// Send a request to the browser wallet to disconnect
@@ -73,6 +80,20 @@ impl Wallet {
             .await
     }
 
+    /// Send a single sign transaction request to the browser wallet containing all the `transactions`.
+    /// The signed transactions are returned in the same order as the `transactions`
+    pub async fn sign_all_transactions(
+        &self,
+        transactions: &[&[u8]],
+        cluster: Option<Cluster>,
+        account: &WalletAccount,
+    ) -> WalletResult<Vec<Vec<u8>>> {
+        self.features
+            .sign_tx
+            .call_sign_all_tx(account, transactions, cluster)
+            .await
+    }
+
     /// Send a sign and send transaction request to the browser wallet.
     pub async fn sign_and_send_transaction(
         &self,
@@ -87,17 +108,65 @@ impl Wallet {
             .await
     }
 
-    /// Get the standard events [Function](web_sys::js_sys::Function) `[standard:events].on`
+    /// Send a single sign and send request to the browser wallet containing all the `transactions`,
+    /// each with its own [SendOptions]. A result is returned for each transaction in the same order
+    /// since some transactions might be sent while others fail.
+    pub async fn sign_and_send_all_transactions(
+        &self,
+        transactions: &[(&[u8], SendOptions)],
+        cluster: Cluster,
+        mode: SignAndSendTransactionMode,
+        account: &WalletAccount,
+    ) -> WalletResult<Vec<WalletResult<Signature>>> {
+        if let Some(fn_exists) = self.features.sign_and_send_all_tx.as_ref() {
+            fn_exists
+                .call_sign_and_send_all_transactions(account, transactions, cluster, mode)
+                .await
+        } else {
+            Err(WalletError::MissingSignAndSendAllTransactionsFunction)
+        }
+    }
+
+    /// Call the function `method` of an extension feature, example `phantom:...`,
+    /// with `input` as the argument. If the function returns a promise, the promise is resolved
+    pub async fn call_extension(
+        &self,
+        feature: &str,
+        method: &str,
+        input: JsValue,
+    ) -> WalletResult<JsValue> {
+        self.features
+            .raw_feature(feature)
+            .ok_or(WalletError::MissingExtensionFeature(feature.to_string()))?
+            .call(method, input)
+            .await
+    }
+
+    /// Call the function of an extension feature described by the [ExtensionMethod] `T`
+    pub async fn call_extension_method<T: ExtensionMethod>(
+        &self,
+        input: &T,
+    ) -> WalletResult<T::Output> {
+        let output = self
+            .call_extension(T::FEATURE, T::METHOD, input.to_input()?)
+            .await?;
+
+        T::from_output(output)
+    }
+
+    /// Subscribe to the `change` event of the wallet using `[standard:events].on`.
+    /// Changes to the chains and features of the wallet update the wallet in `storage`
+    /// and in `connection_info`. The returned [EventsSubscription] removes the listener
     pub async fn call_on_event(
         &self,
         connection_info: ConnectionInfoInner,
+        storage: WalletStorage,
         wallet_name: String,
         sender: WalletEventSender,
-        signal_receiver: Receiver<()>,
-    ) -> WalletResult<()> {
+    ) -> WalletResult<EventsSubscription> {
         self.features
             .events
-            .call_on_event(connection_info, wallet_name, sender, signal_receiver)
+            .call_on_event(connection_info, storage, wallet_name, sender)
             .await
     }
 
@@ -105,26 +174,9 @@ impl Wallet {
     pub fn from_jsvalue(value: JsValue) -> WalletResult<Self> {
         let reflection = Reflection::new(value)?;
 
-        let mut supported_chains = ChainSupport::default();
-
-        let chains_raw = reflection.vec_string_and_filter("chains", "solana:")?;
-        let chains = chains_raw
-            .into_iter()
-            .map(|chain_raw| {
-                let cluster: Cluster = chain_raw.as_str().into();
-                if cluster == Cluster::MainNet {
-                    supported_chains.mainnet = true;
-                } else if cluster == Cluster::DevNet {
-                    supported_chains.devnet = true;
-                } else if cluster == Cluster::TestNet {
-                    supported_chains.testnet = true;
-                } else if cluster == Cluster::LocalNet {
-                    supported_chains.localnet = true;
-                }
-
-                cluster
-            })
-            .collect::<Vec<Cluster>>();
+        let (chains, chain_ids, unrecognized_chains) =
+            Self::parse_chains(reflection.vec_string("chains")?);
+        let supported_chains = ChainSupport::from_chain_ids(&chain_ids);
 
         let name = reflection.string("name")?;
         let version = SemverVersion::parse(&reflection.string("version")?)?;
@@ -134,12 +186,7 @@ impl Wallet {
 
         let data = WalletData::new()
             .set_name(&name)
-            .set_version(
-                wallet_adapter_common::SemverVersion::new()
-                    .set_major(version.major())
-                    .set_minor(version.minor())
-                    .set_patch(version.patch()),
-            )
+            .set_version(version.0)
             .set_icon(icon.as_ref())
             .replace_accounts(
                 accounts
@@ -148,6 +195,8 @@ impl Wallet {
                     .collect(),
             )
             .replace_chains(chains)
+            .replace_chain_ids(chain_ids)
+            .replace_unrecognized_chains(unrecognized_chains)
             .set_supported_features(supported_features)
             .set_supported_chains(supported_chains);
 
@@ -158,6 +207,64 @@ impl Wallet {
         })
     }
 
+    /// Replace the chains of the wallet with the `chains` property of a `change` event.
+    /// Returns `true` if the chains changed
+    pub(crate) fn update_chains(&mut self, chains_raw: Vec<String>) -> bool {
+        let (chains, chain_ids, unrecognized_chains) = Self::parse_chains(chains_raw);
+
+        if chain_ids == self.data.chain_ids()
+            && unrecognized_chains == self.data.unrecognized_chains()
+        {
+            return false;
+        }
+
+        let supported_chains = ChainSupport::from_chain_ids(&chain_ids);
+        self.data = core::mem::take(&mut self.data)
+            .replace_chains(chains)
+            .replace_chain_ids(chain_ids)
+            .replace_unrecognized_chains(unrecognized_chains)
+            .set_supported_chains(supported_chains);
+
+        true
+    }
+
+    /// Replace the features of the wallet with the `features` property of a `change` event.
+    /// Returns `true` if the supported features changed
+    pub(crate) fn update_features(&mut self, reflection: &Reflection) -> WalletResult<bool> {
+        let (features, supported_features) = Features::parse(reflection)?;
+
+        let changed = &supported_features != self.data.supported_features()
+            || features.extensions() != self.features.extensions()
+            || features.unknown_features() != self.features.unknown_features();
+
+        self.features = features;
+        self.data = core::mem::take(&mut self.data).set_supported_features(supported_features);
+
+        Ok(changed)
+    }
+
+    fn parse_chains(chains_raw: Vec<String>) -> (Vec<Cluster>, Vec<ChainId>, Vec<String>) {
+        let mut chains = Vec::<Cluster>::default();
+        let mut chain_ids = Vec::<ChainId>::default();
+        let mut unrecognized_chains = Vec::<String>::default();
+
+        chains_raw
+            .into_iter()
+            .for_each(|chain_raw| match ChainId::parse(&chain_raw) {
+                Ok(chain_id) => {
+                    match chain_id.cluster() {
+                        Some(cluster) => chains.push(cluster),
+                        None => unrecognized_chains.push(chain_raw),
+                    }
+
+                    chain_ids.push(chain_id);
+                }
+                Err(_) => unrecognized_chains.push(chain_raw),
+            });
+
+        (chains, chain_ids, unrecognized_chains)
+    }
+
     fn get_accounts(reflection: &Reflection, key: &str) -> WalletResult<Vec<WalletAccount>> {
         let accounts_raw = reflection.reflect_inner(key)?;
 
@@ -184,6 +291,23 @@ impl Wallet {
         self.data.chains()
     }
 
+    /// Get all the chains advertised by the wallet in the CAIP-2 `namespace:reference` format,
+    /// including chains that are not Solana clusters
+    pub fn chain_ids(&self) -> &[ChainId] {
+        self.data.chain_ids()
+    }
+
+    /// Check whether the wallet advertises the chain
+    pub fn supports_chain(&self, chain_id: &ChainId) -> bool {
+        self.data.supports_chain(chain_id)
+    }
+
+    /// Get the chains advertised by the wallet that are not a known [Cluster].
+    /// These are kept as is instead of being mapped to a cluster they may not be
+    pub fn unrecognized_chains(&self) -> &[String] {
+        self.data.unrecognized_chains()
+    }
+
     /// Check whether the wallet supports mainnet cluster
     pub fn mainnet(&self) -> bool {
         self.data.mainnet()
@@ -234,11 +358,26 @@ impl Wallet {
         self.data.solana_sign_and_send_transaction()
     }
 
+    /// Check whether the wallet supports `solana:signAndSendAllTransactions` feature
+    pub fn solana_sign_and_send_all_transactions(&self) -> bool {
+        self.data.solana_sign_and_send_all_transactions()
+    }
+
     /// Check whether the wallet supports `solana:signTransaction` feature
     pub fn solana_sign_transaction(&self) -> bool {
         self.data.solana_sign_transaction()
     }
 
+    /// Get the transaction versions supported by the `solana:signTransaction` feature
+    pub fn sign_transaction_versions(&self) -> &BTreeSet<TransactionVersion> {
+        &self.features.sign_tx.supported_versions
+    }
+
+    /// Get the transaction versions supported by the `solana:signAndSendTransaction` feature
+    pub fn sign_and_send_transaction_versions(&self) -> &BTreeSet<TransactionVersion> {
+        &self.features.sign_and_send_tx.supported_versions
+    }
+
     /// Get the optional wallet icon
     pub fn icon(&self) -> Option<&Cow<'static, str>> {
         self.data.icon()
@@ -255,4 +394,64 @@ impl Wallet {
 
         SemverVersion(version.clone())
     }
+
+    /// Check whether the wallet standard version of the wallet is compatible
+    /// with [WALLET_STANDARD_VERSION](crate::WALLET_STANDARD_VERSION)
+    pub fn supports_wallet_standard(&self) -> bool {
+        self.data.supports_wallet_standard()
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "mock")]
+#[cfg(target_arch = "wasm32")]
+mod wallet_tests {
+    use wasm_bindgen_test::*;
+
+    use crate::{
+        mock::test_utils::mock_with_account, ChainId, Cluster, MockFeature, Wallet, WalletError,
+    };
+
+    #[wasm_bindgen_test]
+    fn parses_configured_features() {
+        let mock = mock_with_account("Mock Features")
+            .remove_feature(MockFeature::SignIn)
+            .set_chains(&["solana:devnet"]);
+
+        let wallet = Wallet::from_jsvalue(mock.to_jsvalue().unwrap()).unwrap();
+        assert!(!wallet.solana_signin());
+        assert!(wallet.devnet());
+        assert!(!wallet.mainnet());
+    }
+
+    #[wasm_bindgen_test]
+    fn keeps_unrecognized_chains() {
+        let mock = mock_with_account("Mock Chains").set_chains(&[
+            "solana:mainnet-beta",
+            "solana:devnett",
+            "eip155:1",
+        ]);
+
+        let wallet = Wallet::from_jsvalue(mock.to_jsvalue().unwrap()).unwrap();
+        assert_eq!(wallet.chains(), &[Cluster::MainNet]);
+        assert!(wallet.mainnet());
+        assert!(!wallet.devnet());
+        assert_eq!(
+            wallet.unrecognized_chains(),
+            &["solana:devnett".to_string(), "eip155:1".to_string()]
+        );
+        assert_eq!(wallet.chain_ids().len(), 3);
+        assert!(wallet.supports_chain(&"eip155:1".parse::<ChainId>().unwrap()));
+        assert!(!wallet.supports_chain(&Cluster::DevNet.chain_id()));
+    }
+
+    #[wasm_bindgen_test]
+    fn rejects_invalid_wallet_version() {
+        let mock = mock_with_account("Mock Version").set_version("1.0");
+
+        assert_eq!(
+            Wallet::from_jsvalue(mock.to_jsvalue().unwrap()),
+            Err(WalletError::InvalidWalletVersion("1.0".to_string()))
+        );
+    }
 }
@@ -1,10 +1,10 @@
 use std::borrow::Cow;
 
 use wallet_adapter_common::{
-    chains::ChainSupport,
-    clusters::Cluster,
+    chains::{ChainId, ChainSupport},
     feature_support::FeatureSupport,
     standardized_events::{
+        SOLANA_SIGN_AND_SEND_ALL_TRANSACTIONS_IDENTIFIER,
         SOLANA_SIGN_AND_SEND_TRANSACTION_IDENTIFIER, SOLANA_SIGN_IN_IDENTIFIER,
         SOLANA_SIGN_MESSAGE_IDENTIFIER, SOLANA_SIGN_TRANSACTION_IDENTIFIER,
         STANDARD_CONNECT_IDENTIFIER, STANDARD_DISCONNECT_IDENTIFIER, STANDARD_EVENTS_IDENTIFIER,
@@ -101,27 +101,15 @@ impl WalletAccount {
         let chains = reflection.vec_string_accept_undefined("chains")?;
         let features = reflection.vec_string_accept_undefined("features")?;
 
-        let mut supported_chains = ChainSupport::default();
-
-        chains.iter().try_for_each(|chain| {
-            if chain.as_str() == Cluster::MainNet.chain() {
-                supported_chains.mainnet = true;
-            } else if chain.as_str() == Cluster::DevNet.chain() {
-                supported_chains.devnet = true;
-            } else if chain.as_str() == Cluster::TestNet.chain() {
-                supported_chains.testnet = true;
-            } else if chain.as_str() == Cluster::LocalNet.chain() {
-                supported_chains.localnet = true;
-            } else {
-                return Err(WalletError::UnsupportedChain(chain.to_owned()));
-            }
-
-            Ok(())
-        })?;
+        let chain_ids = chains
+            .iter()
+            .filter_map(|chain| ChainId::parse(chain).ok())
+            .collect::<Vec<ChainId>>();
+        let supported_chains = ChainSupport::from_chain_ids(&chain_ids);
 
         let mut supported_features = FeatureSupport::default();
 
-        features.iter().try_for_each(|feature| {
+        features.iter().for_each(|feature| {
             if feature.as_str() == STANDARD_CONNECT_IDENTIFIER {
                 supported_features.connect = true;
             } else if feature.as_str() == STANDARD_DISCONNECT_IDENTIFIER {
@@ -132,16 +120,14 @@ impl WalletAccount {
                 supported_features.sign_in = true;
             } else if feature.as_str() == SOLANA_SIGN_AND_SEND_TRANSACTION_IDENTIFIER {
                 supported_features.sign_and_send_tx = true;
+            } else if feature.as_str() == SOLANA_SIGN_AND_SEND_ALL_TRANSACTIONS_IDENTIFIER {
+                supported_features.sign_and_send_all_tx = true;
             } else if feature.as_str() == SOLANA_SIGN_TRANSACTION_IDENTIFIER {
                 supported_features.sign_tx = true;
             } else if feature.as_str() == SOLANA_SIGN_MESSAGE_IDENTIFIER {
                 supported_features.sign_message = true;
-            } else {
-                return Err(WalletError::UnsupportedWalletFeature(feature.to_owned()));
             }
-
-            Ok(())
-        })?;
+        });
 
         let icon = WalletIcon::from_jsvalue(&reflection)?;
 
@@ -172,6 +158,16 @@ impl WalletAccount {
         })
     }
 
+    /// The chains supported by the account in the CAIP-2 `namespace:reference` format
+    pub fn chain_ids(&self) -> Vec<ChainId> {
+        self.account.chain_ids()
+    }
+
+    /// Checks if the account supports the chain
+    pub fn supports_chain(&self, chain_id: &ChainId) -> bool {
+        self.account.supports_chain(chain_id)
+    }
+
     /// Checks if MainNet is supported
     pub fn mainnet(&self) -> bool {
         self.account.supported_chains.mainnet
@@ -222,6 +218,11 @@ impl WalletAccount {
         self.account.supported_features.sign_and_send_tx
     }
 
+    /// Checks if `solana:signAndSendAllTransactions` is supported
+    pub fn solana_sign_and_send_all_transactions(&self) -> bool {
+        self.account.supported_features.sign_and_send_all_tx
+    }
+
     /// Checks if `solana:signTransaction` is supported
     pub fn solana_sign_transaction(&self) -> bool {
         self.account.supported_features.sign_tx
@@ -1,3 +1,5 @@
+use crate::{clusters::Cluster, WalletUtilsError, WalletUtilsResult};
+
 /// Used as a helper struct to contain all the chains supported by a wallet
 /// as defined by the wallet standard
 #[derive(Debug, Default, PartialEq, Eq, PartialOrd, Ord, Clone, Hash)]
@@ -11,3 +13,183 @@ pub struct ChainSupport {
     /// Local Net cluster
     pub localnet: bool,
 }
+
+impl ChainSupport {
+    /// Set the Solana clusters that are in the `chain_ids`, other chains are ignored
+    pub fn from_chain_ids<'a>(chain_ids: impl IntoIterator<Item = &'a ChainId>) -> Self {
+        let mut supported_chains = Self::default();
+
+        chain_ids
+            .into_iter()
+            .filter_map(ChainId::cluster)
+            .for_each(|cluster| match cluster {
+                Cluster::MainNet => supported_chains.mainnet = true,
+                Cluster::DevNet => supported_chains.devnet = true,
+                Cluster::TestNet => supported_chains.testnet = true,
+                Cluster::LocalNet => supported_chains.localnet = true,
+                Cluster::Custom(_) => (),
+            });
+
+        supported_chains
+    }
+
+    /// Check that every cluster in `required` is supported
+    pub fn contains(&self, required: &ChainSupport) -> bool {
+        (!required.mainnet || self.mainnet)
+            && (!required.devnet || self.devnet)
+            && (!required.testnet || self.testnet)
+            && (!required.localnet || self.localnet)
+    }
+}
+
+/// A chain identifier in the [CAIP-2](https://chainagnostic.org/CAIPs/caip-2) format
+/// `namespace:reference` like `solana:mainnet` or `eip155:1`.
+/// Wallets following the wallet standard can advertise chains of any namespace
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ChainId {
+    namespace: String,
+    reference: String,
+}
+
+impl ChainId {
+    /// The namespace of Solana chains
+    pub const SOLANA_NAMESPACE: &'static str = "solana";
+
+    /// Create a chain identifier. The `namespace` must be 3 to 8 characters of `-`, `a-z` or `0-9`
+    /// and the `reference` 1 to 32 characters of `-`, `_`, `a-z`, `A-Z` or `0-9`
+    pub fn new(namespace: &str, reference: &str) -> WalletUtilsResult<Self> {
+        let valid_namespace = (3..=8).contains(&namespace.len())
+            && namespace
+                .bytes()
+                .all(|byte| byte == b'-' || byte.is_ascii_lowercase() || byte.is_ascii_digit());
+        let valid_reference = (1..=32).contains(&reference.len())
+            && reference
+                .bytes()
+                .all(|byte| byte == b'-' || byte == b'_' || byte.is_ascii_alphanumeric());
+
+        if !valid_namespace || !valid_reference {
+            return Err(WalletUtilsError::InvalidChainId(format!(
+                "{namespace}:{reference}"
+            )));
+        }
+
+        Ok(Self {
+            namespace: namespace.to_string(),
+            reference: reference.to_string(),
+        })
+    }
+
+    /// Parse a chain identifier in the `namespace:reference` format
+    pub fn parse(value: &str) -> WalletUtilsResult<Self> {
+        let (namespace, reference) = value
+            .split_once(':')
+            .ok_or(WalletUtilsError::InvalidChainId(value.to_string()))?;
+
+        Self::new(namespace, reference)
+    }
+
+    /// Get the namespace, example `solana`
+    pub fn namespace(&self) -> &str {
+        self.namespace.as_str()
+    }
+
+    /// Get the reference within the namespace, example `mainnet`
+    pub fn reference(&self) -> &str {
+        self.reference.as_str()
+    }
+
+    /// Whether the chain is in the `solana` namespace
+    pub fn is_solana(&self) -> bool {
+        self.namespace == Self::SOLANA_NAMESPACE
+    }
+
+    /// The Solana [Cluster] of this chain, if it is one
+    pub fn cluster(&self) -> Option<Cluster> {
+        if self.is_solana() {
+            Cluster::try_from(self.to_string().as_str()).ok()
+        } else {
+            None
+        }
+    }
+}
+
+impl core::fmt::Display for ChainId {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}:{}", self.namespace, self.reference)
+    }
+}
+
+impl TryFrom<&str> for ChainId {
+    type Error = WalletUtilsError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Self::parse(value)
+    }
+}
+
+impl core::str::FromStr for ChainId {
+    type Err = WalletUtilsError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl From<&Cluster> for ChainId {
+    fn from(value: &Cluster) -> Self {
+        let (namespace, reference) = value
+            .chain()
+            .split_once(':')
+            .unwrap_or((Self::SOLANA_NAMESPACE, value.chain()));
+
+        Self {
+            namespace: namespace.to_string(),
+            reference: reference.to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod chain_id_tests {
+    use super::*;
+
+    #[test]
+    fn parses_caip2_identifiers() {
+        let mainnet = ChainId::parse("solana:mainnet").unwrap();
+        assert!(mainnet.is_solana());
+        assert_eq!(mainnet.reference(), "mainnet");
+        assert_eq!(mainnet.cluster(), Some(Cluster::MainNet));
+        assert_eq!(ChainId::from(&Cluster::MainNet), mainnet);
+
+        let ethereum: ChainId = "eip155:1".parse().unwrap();
+        assert_eq!(ethereum.namespace(), "eip155");
+        assert_eq!(ethereum.cluster(), None);
+        assert_eq!(ethereum.to_string(), "eip155:1");
+
+        assert_eq!(
+            ChainId::parse("solana:5eykt4UsFv8P8NJdTREpY1vzqKqZKvdp").map(|chain| chain.cluster()),
+            Ok(None)
+        );
+        assert!(ChainId::parse("solana").is_err());
+        assert!(ChainId::parse("so:mainnet").is_err());
+        assert!(ChainId::parse("Solana:mainnet").is_err());
+        assert!(ChainId::parse("solana:").is_err());
+    }
+
+    #[test]
+    fn chain_support_from_chain_ids() {
+        let chain_ids = ["solana:devnet", "solana:localnet", "eip155:1"]
+            .iter()
+            .map(|chain| ChainId::parse(chain).unwrap())
+            .collect::<Vec<ChainId>>();
+
+        assert_eq!(
+            ChainSupport::from_chain_ids(&chain_ids),
+            ChainSupport {
+                devnet: true,
+                localnet: true,
+                ..Default::default()
+            }
+        );
+    }
+}
@@ -7,6 +7,15 @@ pub const TESTNET_ENDPOINT: &str = "https://api.testnet.solana.com";
 /// Solana Localnet cluster
 pub const LOCALNET_ENDPOINT: &str = "http://localhost:8899";
 
+/// Solana Mainnet cluster websocket endpoint
+pub const MAINNET_WS_ENDPOINT: &str = "wss://api.mainnet-beta.solana.com";
+/// Solana Devnet cluster websocket endpoint
+pub const DEVNET_WS_ENDPOINT: &str = "wss://api.devnet.solana.com";
+/// Solana Testnet cluster websocket endpoint
+pub const TESTNET_WS_ENDPOINT: &str = "wss://api.testnet.solana.com";
+/// Solana Localnet cluster websocket endpoint
+pub const LOCALNET_WS_ENDPOINT: &str = "ws://localhost:8900";
+
 /// Solana Mainnet cluster,  [https://api.mainnet-beta.solana.com](https://api.mainnet-beta.solana.com)
 pub const MAINNET_IDENTIFIER: &str = "solana:mainnet";
 /// Solana Devnet cluster, e.g. [https://api.devnet.solana.com](https://api.devnet.solana.com)
@@ -25,8 +34,10 @@ pub const TESTNET: &str = "testnet";
 /// Solana Localnet cluster, e.g. [http://localhost:8899](http://localhost:8899)
 pub const LOCALNET: &str = "localnet";
 
+use crate::{chains::ChainId, WalletUtilsError, WalletUtilsResult};
+
 /// Solana Clusters
-#[derive(Debug, PartialEq, Eq, Default, PartialOrd, Ord, Clone, Copy, Hash)]
+#[derive(Debug, PartialEq, Eq, Default, PartialOrd, Ord, Clone, Hash)]
 pub enum Cluster {
     /// Solana Mainnet cluster,  [https://api.mainnet-beta.solana.com](https://api.mainnet-beta.solana.com)
     MainNet,
@@ -37,9 +48,16 @@ pub enum Cluster {
     TestNet,
     /// Solana Localnet cluster, e.g. [http://localhost:8899](http://localhost:8899)
     LocalNet,
+    /// A user defined cluster like a paid RPC provider or a private validator
+    Custom(CustomCluster),
 }
 
 impl Cluster {
+    /// Create a [Cluster::Custom] cluster, see [CustomCluster::new]
+    pub fn custom(name: &str, endpoint: &str, chain: &str) -> WalletUtilsResult<Self> {
+        Ok(Self::Custom(CustomCluster::new(name, endpoint, chain)?))
+    }
+
     /// A Solana endpoint URI
     pub fn endpoint(&self) -> &str {
         match self {
@@ -47,28 +65,162 @@ impl Cluster {
             Cluster::DevNet => DEVNET_ENDPOINT,
             Cluster::TestNet => TESTNET_ENDPOINT,
             Cluster::LocalNet => LOCALNET_ENDPOINT,
+            Cluster::Custom(custom) => custom.endpoint(),
         }
     }
 
-    /// A Solana cluster identifier
+    /// A Solana websocket endpoint URI if one is known
+    pub fn ws_endpoint(&self) -> Option<&str> {
+        match self {
+            Cluster::MainNet => Some(MAINNET_WS_ENDPOINT),
+            Cluster::DevNet => Some(DEVNET_WS_ENDPOINT),
+            Cluster::TestNet => Some(TESTNET_WS_ENDPOINT),
+            Cluster::LocalNet => Some(LOCALNET_WS_ENDPOINT),
+            Cluster::Custom(custom) => custom.ws_endpoint(),
+        }
+    }
+
+    /// A Solana cluster identifier. A custom cluster returns
+    /// the identifier of the cluster it shares a genesis block with
     pub fn chain(&self) -> &str {
         match self {
             Cluster::MainNet => MAINNET_IDENTIFIER,
             Cluster::DevNet => DEVNET_IDENTIFIER,
             Cluster::TestNet => TESTNET_IDENTIFIER,
             Cluster::LocalNet => LOCALNET_IDENTIFIER,
+            Cluster::Custom(custom) => custom.chain(),
         }
     }
 
-    /// A Solana cluster identifier as a &str
+    /// A Solana cluster identifier as a &str. A custom cluster returns its name
     pub fn display(&self) -> &str {
         match self {
             Cluster::MainNet => MAINNET,
             Cluster::DevNet => DEVNET,
             Cluster::TestNet => TESTNET,
             Cluster::LocalNet => LOCALNET,
+            Cluster::Custom(custom) => custom.name(),
+        }
+    }
+
+    /// The public cluster that shares a genesis block with this cluster.
+    /// A custom cluster returns the cluster of its `solana:*` chain identifier
+    pub fn genesis(&self) -> Cluster {
+        match self {
+            Cluster::Custom(custom) => custom.genesis(),
+            cluster => cluster.clone(),
         }
     }
+
+    /// The [ChainId] of the cluster's `solana:*` chain identifier
+    pub fn chain_id(&self) -> ChainId {
+        self.into()
+    }
+
+    /// Whether this is a user defined cluster
+    pub fn is_custom(&self) -> bool {
+        matches!(self, Cluster::Custom(_))
+    }
+}
+
+/// A user defined cluster like a paid RPC provider or a private validator.
+/// The `chain` is the `solana:*` identifier of the public cluster it shares a genesis block with,
+/// which is what gets sent to the browser wallet
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Hash)]
+pub struct CustomCluster {
+    name: String,
+    endpoint: String,
+    ws_endpoint: Option<String>,
+    genesis: GenesisCluster,
+}
+
+impl CustomCluster {
+    /// Create a custom cluster. The `chain` can be a `solana:*` identifier
+    /// like `solana:mainnet` or any other value [Cluster] can be parsed from like `mainnet`
+    pub fn new(name: &str, endpoint: &str, chain: &str) -> WalletUtilsResult<Self> {
+        let genesis = GenesisCluster::try_from(chain)?;
+
+        Ok(Self {
+            name: name.to_string(),
+            endpoint: endpoint.to_string(),
+            ws_endpoint: Option::default(),
+            genesis,
+        })
+    }
+
+    /// Set the websocket endpoint
+    pub fn set_ws_endpoint(&mut self, ws_endpoint: &str) -> &mut Self {
+        self.ws_endpoint.replace(ws_endpoint.to_string());
+
+        self
+    }
+
+    /// Get the name
+    pub fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    /// Get the HTTP endpoint
+    pub fn endpoint(&self) -> &str {
+        self.endpoint.as_str()
+    }
+
+    /// Get the websocket endpoint
+    pub fn ws_endpoint(&self) -> Option<&str> {
+        self.ws_endpoint.as_deref()
+    }
+
+    /// Get the `solana:*` chain identifier
+    pub fn chain(&self) -> &str {
+        self.genesis.chain()
+    }
+
+    /// The public cluster that shares a genesis block with this cluster
+    pub fn genesis(&self) -> Cluster {
+        self.genesis.into()
+    }
+}
+
+/// The public cluster a [CustomCluster] shares a genesis block with
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
+pub enum GenesisCluster {
+    /// Solana Mainnet cluster
+    MainNet,
+    /// Solana Devnet cluster
+    DevNet,
+    /// Solana Testnet cluster
+    TestNet,
+    /// Solana Localnet cluster
+    LocalNet,
+}
+
+impl GenesisCluster {
+    /// The `solana:*` chain identifier
+    pub fn chain(&self) -> &'static str {
+        match self {
+            GenesisCluster::MainNet => MAINNET_IDENTIFIER,
+            GenesisCluster::DevNet => DEVNET_IDENTIFIER,
+            GenesisCluster::TestNet => TESTNET_IDENTIFIER,
+            GenesisCluster::LocalNet => LOCALNET_IDENTIFIER,
+        }
+    }
+}
+
+impl From<GenesisCluster> for Cluster {
+    fn from(value: GenesisCluster) -> Self {
+        match value {
+            GenesisCluster::MainNet => Self::MainNet,
+            GenesisCluster::DevNet => Self::DevNet,
+            GenesisCluster::TestNet => Self::TestNet,
+            GenesisCluster::LocalNet => Self::LocalNet,
+        }
+    }
+}
+
+impl From<CustomCluster> for Cluster {
+    fn from(value: CustomCluster) -> Self {
+        Self::Custom(value)
+    }
 }
 
 impl core::fmt::Display for Cluster {
@@ -77,22 +229,52 @@ impl core::fmt::Display for Cluster {
     }
 }
 
-impl From<&str> for Cluster {
-    fn from(value: &str) -> Self {
-        match value {
-            MAINNET_IDENTIFIER => Self::MainNet,
-            DEVNET_IDENTIFIER => Self::DevNet,
-            TESTNET_IDENTIFIER => Self::TestNet,
-            LOCALNET_IDENTIFIER => Self::LocalNet,
-            MAINNET_ENDPOINT => Self::MainNet,
-            DEVNET_ENDPOINT => Self::DevNet,
-            TESTNET_ENDPOINT => Self::TestNet,
-            LOCALNET_ENDPOINT => Self::LocalNet,
-            MAINNET => Self::MainNet,
-            DEVNET => Self::DevNet,
-            TESTNET => Self::TestNet,
-            LOCALNET => Self::LocalNet,
-            _ => Self::DevNet,
-        }
+/// Known aliases of the public clusters, like the `mainnet-beta` name used by the Solana CLI
+const CLUSTER_ALIASES: &[(&str, GenesisCluster)] = &[
+    (MAINNET_IDENTIFIER, GenesisCluster::MainNet),
+    (MAINNET_ENDPOINT, GenesisCluster::MainNet),
+    (MAINNET, GenesisCluster::MainNet),
+    ("solana:mainnet-beta", GenesisCluster::MainNet),
+    ("mainnet-beta", GenesisCluster::MainNet),
+    (DEVNET_IDENTIFIER, GenesisCluster::DevNet),
+    (DEVNET_ENDPOINT, GenesisCluster::DevNet),
+    (DEVNET, GenesisCluster::DevNet),
+    (TESTNET_IDENTIFIER, GenesisCluster::TestNet),
+    (TESTNET_ENDPOINT, GenesisCluster::TestNet),
+    (TESTNET, GenesisCluster::TestNet),
+    (LOCALNET_IDENTIFIER, GenesisCluster::LocalNet),
+    (LOCALNET_ENDPOINT, GenesisCluster::LocalNet),
+    (LOCALNET, GenesisCluster::LocalNet),
+    ("localhost", GenesisCluster::LocalNet),
+    ("http://127.0.0.1:8899", GenesisCluster::LocalNet),
+];
+
+impl TryFrom<&str> for GenesisCluster {
+    type Error = WalletUtilsError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let trimmed = value.trim();
+
+        CLUSTER_ALIASES
+            .iter()
+            .find(|(alias, _)| alias.eq_ignore_ascii_case(trimmed))
+            .map(|(_, cluster)| *cluster)
+            .ok_or(WalletUtilsError::UnsupportedChain(value.to_string()))
+    }
+}
+
+impl TryFrom<&str> for Cluster {
+    type Error = WalletUtilsError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        GenesisCluster::try_from(value).map(Self::from)
+    }
+}
+
+impl core::str::FromStr for Cluster {
+    type Err = WalletUtilsError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::try_from(value)
     }
 }
@@ -46,4 +46,25 @@ pub enum WalletUtilsError {
     /// The message signed by the wallet is not the same as the message sent to the wallet for signing
     #[error("The message signed by the wallet is not the same as the message sent to the wallet for signing")]
     MessageResponseMismatch,
+    /// The bytes are not a transaction serialized in the wire format
+    #[error("The bytes are not a transaction serialized in the wire format")]
+    InvalidTransactionBytes,
+    /// The version of the transaction message cannot be decoded or encoded
+    #[error("The transaction version `{0}` is not supported")]
+    TransactionVersionNotSupported(String),
+    /// Legacy messages cannot load accounts from address lookup tables
+    #[error("A legacy message cannot contain address table lookups")]
+    LegacyMessageWithAddressTableLookups,
+    /// The chain identifier does not map to a Solana cluster
+    #[error("The chain `{0}` is not a Solana cluster")]
+    UnsupportedChain(String),
+    /// The chain identifier is not in the CAIP-2 `namespace:reference` format
+    #[error("The chain identifier `{0}` is not in the `namespace:reference` format")]
+    InvalidChainId(String),
+    /// The version is not a valid SemVer version
+    #[error("`{0}` is not a valid SemVer version")]
+    InvalidSemverVersion(String),
+    /// The version requirement is not valid
+    #[error("`{0}` is not a valid SemVer version requirement")]
+    InvalidSemverRequirement(String),
 }
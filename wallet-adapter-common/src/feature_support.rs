@@ -1,3 +1,17 @@
+use crate::{standardized_events::*, SemverRequirement, SemverVersion};
+
+/// The version requirement each feature's `version` must satisfy to be used by this library
+pub const FEATURE_VERSION_REQUIREMENTS: &[(&str, &str)] = &[
+    (STANDARD_CONNECT_IDENTIFIER, "^1.0.0"),
+    (STANDARD_DISCONNECT_IDENTIFIER, "^1.0.0"),
+    (STANDARD_EVENTS_IDENTIFIER, "^1.0.0"),
+    (SOLANA_SIGN_IN_IDENTIFIER, "^1.0.0"),
+    (SOLANA_SIGN_MESSAGE_IDENTIFIER, "^1.0.0"),
+    (SOLANA_SIGN_AND_SEND_TRANSACTION_IDENTIFIER, "^1.0.0"),
+    (SOLANA_SIGN_AND_SEND_ALL_TRANSACTIONS_IDENTIFIER, "^1.0.0"),
+    (SOLANA_SIGN_TRANSACTION_IDENTIFIER, "^1.0.0"),
+];
+
 /// Used as a helper struct to contain all the features supported by a wallet
 /// as defined by the wallet standard
 #[derive(Debug, Default, PartialEq, Eq, PartialOrd, Ord, Clone, Hash)]
@@ -14,6 +28,65 @@ pub struct FeatureSupport {
     pub sign_message: bool,
     /// 'solana:signAndSendTransaction'
     pub sign_and_send_tx: bool,
+    /// 'solana:signAndSendAllTransactions'
+    pub sign_and_send_all_tx: bool,
     /// 'solana:signTransaction'
     pub sign_tx: bool,
 }
+
+impl FeatureSupport {
+    /// The version requirement of a feature from [FEATURE_VERSION_REQUIREMENTS],
+    /// [None] if the feature is not one this library supports
+    pub fn version_requirement(feature: &str) -> Option<SemverRequirement> {
+        FEATURE_VERSION_REQUIREMENTS
+            .iter()
+            .find(|(identifier, _)| *identifier == feature)
+            .and_then(|(_, requirement)| SemverRequirement::parse(requirement).ok())
+    }
+
+    /// Check whether the `version` of a feature satisfies its `requirement`.
+    /// A pre-release is compatible if its release is, example `1.1.0-beta.1` for `^1.0.0`,
+    /// since wallets ship pre-releases of the features of a supported major version
+    pub fn is_compatible(requirement: &SemverRequirement, version: &SemverVersion) -> bool {
+        version.satisfies(requirement)
+            || (version.is_pre_release()
+                && version
+                    .clone()
+                    .set_pre_release(Vec::default())
+                    .satisfies(requirement))
+    }
+
+    /// Check that every feature in `required` is supported
+    pub fn contains(&self, required: &FeatureSupport) -> bool {
+        (!required.connect || self.connect)
+            && (!required.disconnect || self.disconnect)
+            && (!required.events || self.events)
+            && (!required.sign_in || self.sign_in)
+            && (!required.sign_message || self.sign_message)
+            && (!required.sign_and_send_tx || self.sign_and_send_tx)
+            && (!required.sign_and_send_all_tx || self.sign_and_send_all_tx)
+            && (!required.sign_tx || self.sign_tx)
+    }
+}
+
+#[cfg(test)]
+mod feature_support_tests {
+    use super::*;
+
+    #[test]
+    fn accepts_pre_releases_of_supported_versions() {
+        let requirement = FeatureSupport::version_requirement(STANDARD_CONNECT_IDENTIFIER).unwrap();
+        let compatible = |version: &str| {
+            FeatureSupport::is_compatible(&requirement, &SemverVersion::parse(version).unwrap())
+        };
+
+        assert!(compatible("1.0.0"));
+        assert!(compatible("1.2.0"));
+        assert!(compatible("1.0.0-beta"));
+        assert!(compatible("1.1.0-rc.1"));
+        assert!(!compatible("0.9.0"));
+        assert!(!compatible("0.9.0-beta"));
+        assert!(!compatible("2.0.0"));
+        assert!(!compatible("2.0.0-alpha.1"));
+    }
+}
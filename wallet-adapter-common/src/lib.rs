@@ -12,12 +12,21 @@ pub use wallet_account::*;
 mod wallet;
 pub use wallet::*;
 
+mod wallet_list;
+pub use wallet_list::*;
+
+mod wallet_catalog;
+pub use wallet_catalog::*;
+
 mod version;
 pub use version::*;
 
 mod utils;
 pub use utils::*;
 
+mod transaction_version;
+pub use transaction_version::*;
+
 /// Feature support struct
 pub mod feature_support;
 
@@ -33,6 +42,9 @@ pub mod chains;
 /// Cluster identifiers for Solana `wallet-adapter` standard
 pub mod clusters;
 
+/// Decoding and encoding of legacy and version zero transactions in the wire format
+pub mod transaction;
+
 #[cfg(test)]
 mod chain_tests {
     use super::clusters::*;
@@ -52,21 +64,41 @@ mod chain_tests {
 
     #[test]
     fn valid_chain() {
-        assert_eq!(Cluster::MainNet, "solana:mainnet".into());
-        assert_eq!(Cluster::DevNet, "solana:devnet".into());
-        assert_eq!(Cluster::TestNet, "solana:testnet".into());
-        assert_eq!(Cluster::LocalNet, "solana:localnet".into());
-        assert!(Cluster::DevNet == "solana:localnet2".into());
+        assert_eq!(Ok(Cluster::MainNet), "solana:mainnet".try_into());
+        assert_eq!(Ok(Cluster::DevNet), "solana:devnet".try_into());
+        assert_eq!(Ok(Cluster::TestNet), "solana:testnet".try_into());
+        assert_eq!(Ok(Cluster::LocalNet), "solana:localnet".try_into());
+        assert_eq!(
+            Cluster::try_from("solana:localnet2"),
+            Err(crate::WalletUtilsError::UnsupportedChain(
+                "solana:localnet2".to_string()
+            ))
+        );
 
         assert_eq!(
-            Cluster::MainNet,
-            "https://api.mainnet-beta.solana.com".into()
+            Ok(Cluster::MainNet),
+            "https://api.mainnet-beta.solana.com".try_into()
         );
-        assert_eq!(Cluster::DevNet, "https://api.devnet.solana.com".into());
-        assert_eq!(Cluster::TestNet, "https://api.testnet.solana.com".into());
-        assert_eq!(Cluster::LocalNet, "http://localhost:8899".into());
-        assert!(Cluster::DevNet == "https://localhost:8899".into());
-        assert!(Cluster::DevNet == "https://cluster.foo".into());
+        assert_eq!(
+            Ok(Cluster::DevNet),
+            "https://api.devnet.solana.com".try_into()
+        );
+        assert_eq!(
+            Ok(Cluster::TestNet),
+            "https://api.testnet.solana.com".try_into()
+        );
+        assert_eq!(Ok(Cluster::LocalNet), "http://localhost:8899".try_into());
+        assert!(Cluster::try_from("https://localhost:8899").is_err());
+        assert!(Cluster::try_from("https://cluster.foo").is_err());
+    }
+
+    #[test]
+    fn cluster_aliases() {
+        assert_eq!("solana:mainnet-beta".parse(), Ok(Cluster::MainNet));
+        assert_eq!("mainnet-beta".parse(), Ok(Cluster::MainNet));
+        assert_eq!(" DevNet ".parse(), Ok(Cluster::DevNet));
+        assert_eq!("localhost".parse(), Ok(Cluster::LocalNet));
+        assert!("solana:devnett".parse::<Cluster>().is_err());
     }
 
     #[test]
@@ -82,4 +114,46 @@ mod chain_tests {
         );
         assert_eq!(Cluster::LocalNet.endpoint(), "http://localhost:8899");
     }
+
+    #[test]
+    fn custom_cluster() {
+        let mut custom =
+            CustomCluster::new("Private RPC", "https://rpc.example.com", "mainnet").unwrap();
+        custom.set_ws_endpoint("wss://rpc.example.com");
+        let cluster: Cluster = custom.clone().into();
+
+        assert!(cluster.is_custom());
+        assert_eq!(cluster.display(), "Private RPC");
+        assert_eq!(cluster.endpoint(), "https://rpc.example.com");
+        assert_eq!(cluster.ws_endpoint(), Some("wss://rpc.example.com"));
+        assert_eq!(cluster.chain(), MAINNET_IDENTIFIER);
+        assert_eq!(cluster.genesis(), Cluster::MainNet);
+        assert_eq!(Cluster::DevNet.genesis(), Cluster::DevNet);
+        assert_eq!(
+            CustomCluster::new("Private RPC", "https://rpc.example.com", "solana:mainnet")
+                .unwrap()
+                .genesis(),
+            GenesisCluster::MainNet.into()
+        );
+        assert_eq!(Cluster::from(custom), cluster);
+
+        let endpoint = format!("https://{}.example.com", "runtime");
+        assert_eq!(
+            CustomCluster::new("Runtime RPC", &endpoint, "devnet")
+                .unwrap()
+                .endpoint(),
+            endpoint
+        );
+        assert_eq!(
+            GenesisCluster::try_from("mainnet-beta"),
+            Ok(GenesisCluster::MainNet)
+        );
+
+        assert_eq!(
+            Cluster::custom("Ethereum", "https://rpc.example.com", "eip155:1"),
+            Err(crate::WalletUtilsError::UnsupportedChain(
+                "eip155:1".to_string()
+            ))
+        );
+    }
 }
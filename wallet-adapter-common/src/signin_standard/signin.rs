@@ -351,9 +351,7 @@ impl SigninInput {
 
                 if input.contains("Chain ID") {
                     if let Some((_left, right)) = input.split_once(":") {
-                        let cluster: Cluster = right.trim().into();
-
-                        signin_input.chain_id.replace(cluster);
+                        signin_input.chain_id.replace(Cluster::try_from(right)?);
                     }
                 }
                 if input.contains("Nonce") {
@@ -400,6 +398,57 @@ impl SigninInput {
         }
     }
 
+    /// Build the Sign In With Solana (SIWS) message a wallet signs.
+    /// The `domain` and `address` fields of [Self] take precedence over
+    /// the `default_domain` and `default_address` determined by the wallet
+    pub fn message(&self, default_domain: &str, default_address: &str) -> String {
+        let domain = self.domain.as_deref().unwrap_or(default_domain);
+        let address = self.address.as_deref().unwrap_or(default_address);
+
+        let mut message =
+            String::new() + domain + " wants you to sign in with your Solana account:\n" + address;
+
+        if let Some(statement) = self.statement.as_ref() {
+            message = message + "\n\n" + statement;
+        }
+
+        let mut fields = String::new();
+        [
+            ("URI", self.uri.clone()),
+            ("Version", self.version.clone()),
+            (
+                "Chain ID",
+                self.chain_id
+                    .as_ref()
+                    .map(|cluster| cluster.chain().to_string()),
+            ),
+            ("Nonce", self.nonce.clone()),
+            ("Issued At", self.issued_at_iso8601()),
+            ("Expiration Time", self.expiration_time_iso8601()),
+            ("Not Before", self.not_before_iso8601()),
+            ("Request ID", self.request_id.clone()),
+        ]
+        .into_iter()
+        .for_each(|(label, value)| {
+            if let Some(value) = value {
+                fields.push_str(&(String::from("\n") + label + ": " + &value));
+            }
+        });
+
+        if !self.resources.is_empty() {
+            fields.push_str("\nResources:");
+            self.resources.iter().for_each(|resource| {
+                fields.push_str(&(String::from("\n- ") + resource));
+            });
+        }
+
+        if !fields.is_empty() {
+            message = message + "\n" + &fields;
+        }
+
+        message
+    }
+
     /// An EIP-4361 Request ID.
     /// In addition to using nonce to avoid replay attacks,
     /// dapps can also choose to include a unique signature in the requestId .
@@ -593,3 +642,41 @@ mod signin_input_sanity_checks {
         assert!(signin_input.set_not_before_time_seconds(4).is_ok());
     }
 }
+
+#[cfg(test)]
+mod signin_message_tests {
+    use super::*;
+
+    #[test]
+    fn message_roundtrips_through_parser() {
+        let now = SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+
+        let mut signin_input = SigninInput::new();
+        signin_input
+            .set_domain("localhost:8080")
+            .set_statement("Login To Dev")
+            .set_uri("http://localhost:8080/login")
+            .set_version("1")
+            .set_chain_id(Cluster::DevNet)
+            .set_custom_nonce("nonce-12345678")
+            .unwrap()
+            .set_issued_at(now)
+            .set_request_id("request-1")
+            .add_resources(&["https://example.com", "ipfs://bafy"]);
+        signin_input
+            .set_expiration_time(now, now + Duration::from_secs(60))
+            .unwrap();
+
+        let address = "7EqQdEUFwDZ6rUhkZYFxiKzsAtPc9kfjUNAc5ch4MHQr";
+        let message = signin_input.message("ignored.example", address);
+
+        assert!(message.starts_with(
+            "localhost:8080 wants you to sign in with your Solana account:\n\
+            7EqQdEUFwDZ6rUhkZYFxiKzsAtPc9kfjUNAc5ch4MHQr\n\nLogin To Dev\n\nURI: "
+        ));
+        assert!(message.ends_with("Resources:\n- https://example.com\n- ipfs://bafy"));
+
+        signin_input.set_address(address).unwrap();
+        assert!(signin_input.check_eq(&message).is_ok());
+    }
+}
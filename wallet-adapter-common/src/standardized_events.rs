@@ -11,6 +11,9 @@ pub const STANDARD_CONNECT_IDENTIFIER: &str = "standard:connect";
 pub const STANDARD_DISCONNECT_IDENTIFIER: &str = "standard:disconnect";
 /// Identifier for `solana:signAndSendTransaction`
 pub const SOLANA_SIGN_AND_SEND_TRANSACTION_IDENTIFIER: &str = "solana:signAndSendTransaction";
+/// Identifier for `solana:signAndSendAllTransactions`
+pub const SOLANA_SIGN_AND_SEND_ALL_TRANSACTIONS_IDENTIFIER: &str =
+    "solana:signAndSendAllTransactions";
 /// Identifier for `solana:signTransaction`
 pub const SOLANA_SIGN_TRANSACTION_IDENTIFIER: &str = "solana:signTransaction";
 /// Identifier for `solana:signIn`
@@ -0,0 +1,502 @@
+use crate::{TransactionVersion, WalletUtilsError, WalletUtilsResult};
+
+/// A Solana transaction decoded from the wire format, the format the bytes
+/// passed to and returned from a browser wallet are encoded in
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Transaction {
+    /// The Ed25519 signatures in the same order as the signer account keys of the message
+    pub signatures: Vec<[u8; 64]>,
+    /// The message that is signed
+    pub message: Message,
+}
+
+impl Transaction {
+    /// Decode a legacy or version zero transaction from the wire format.
+    /// The number of signatures must equal the number of signatures the message requires
+    pub fn decode(bytes: &[u8]) -> WalletUtilsResult<Self> {
+        let mut reader = WireReader::new(bytes);
+
+        let signatures_len = reader.compact_u16()?;
+        let signatures = (0..signatures_len)
+            .map(|_| reader.array::<64>())
+            .collect::<WalletUtilsResult<Vec<[u8; 64]>>>()?;
+
+        let message = Message::decode_from(&mut reader)?;
+        reader.finish()?;
+
+        if signatures.len() != message.header.num_required_signatures as usize {
+            return Err(WalletUtilsError::InvalidTransactionBytes);
+        }
+
+        Ok(Self {
+            signatures,
+            message,
+        })
+    }
+
+    /// Encode the transaction into the wire format
+    pub fn encode(&self) -> WalletUtilsResult<Vec<u8>> {
+        let mut bytes = Vec::<u8>::new();
+
+        encode_compact_u16(self.signatures.len(), &mut bytes)?;
+        self.signatures
+            .iter()
+            .for_each(|signature| bytes.extend_from_slice(signature));
+        bytes.extend_from_slice(&self.message.encode()?);
+
+        Ok(bytes)
+    }
+
+    /// The raw message bytes of a transaction in the wire format, these are the bytes the signatures are over
+    pub fn message_bytes(transaction_bytes: &[u8]) -> WalletUtilsResult<&[u8]> {
+        let mut reader = WireReader::new(transaction_bytes);
+
+        let signatures_len = reader.compact_u16()?;
+        reader.take(signatures_len.saturating_mul(64))?;
+
+        Ok(&transaction_bytes[reader.offset..])
+    }
+
+    /// The account keys that are required to sign the transaction
+    /// paired with their signatures
+    pub fn signers(&self) -> impl Iterator<Item = (&[u8; 32], &[u8; 64])> {
+        self.message
+            .signer_keys()
+            .iter()
+            .zip(self.signatures.iter())
+    }
+}
+
+/// The message of a [Transaction]. Legacy and version zero messages share the same layout
+/// except that version zero messages start with a version prefix and end with address table lookups
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Message {
+    /// The version of the message
+    pub version: TransactionVersion,
+    /// The number of signer and read-only accounts
+    pub header: MessageHeader,
+    /// The account keys in the message, signers are first followed by non-signers
+    pub account_keys: Vec<[u8; 32]>,
+    /// The recent blockhash used to determine how long the transaction is valid for
+    pub recent_blockhash: [u8; 32],
+    /// The instructions, each referencing the account keys by index
+    pub instructions: Vec<CompiledInstruction>,
+    /// The address lookup tables used to load more accounts.
+    /// Always empty for legacy messages
+    pub address_table_lookups: Vec<AddressTableLookup>,
+}
+
+impl Message {
+    /// Decode a legacy or version zero message from the wire format
+    pub fn decode(bytes: &[u8]) -> WalletUtilsResult<Self> {
+        let mut reader = WireReader::new(bytes);
+        let message = Self::decode_from(&mut reader)?;
+        reader.finish()?;
+
+        Ok(message)
+    }
+
+    fn decode_from(reader: &mut WireReader) -> WalletUtilsResult<Self> {
+        let version = match reader.peek()? {
+            prefix if prefix & VERSION_PREFIX_MASK == 0 => TransactionVersion::Legacy,
+            prefix => {
+                reader.u8()?;
+                TransactionVersion::Number(prefix & !VERSION_PREFIX_MASK)
+            }
+        };
+
+        if version != TransactionVersion::Legacy && version != TransactionVersion::V0 {
+            return Err(WalletUtilsError::TransactionVersionNotSupported(
+                version.to_string(),
+            ));
+        }
+
+        let header = MessageHeader {
+            num_required_signatures: reader.u8()?,
+            num_readonly_signed_accounts: reader.u8()?,
+            num_readonly_unsigned_accounts: reader.u8()?,
+        };
+
+        let account_keys_len = reader.compact_u16()?;
+        let account_keys = (0..account_keys_len)
+            .map(|_| reader.array::<32>())
+            .collect::<WalletUtilsResult<Vec<[u8; 32]>>>()?;
+
+        let recent_blockhash = reader.array::<32>()?;
+
+        let instructions_len = reader.compact_u16()?;
+        let instructions = (0..instructions_len)
+            .map(|_| {
+                Ok(CompiledInstruction {
+                    program_id_index: reader.u8()?,
+                    accounts: reader.compact_bytes()?,
+                    data: reader.compact_bytes()?,
+                })
+            })
+            .collect::<WalletUtilsResult<Vec<CompiledInstruction>>>()?;
+
+        let address_table_lookups = if version == TransactionVersion::Legacy {
+            Vec::default()
+        } else {
+            let lookups_len = reader.compact_u16()?;
+
+            (0..lookups_len)
+                .map(|_| {
+                    Ok(AddressTableLookup {
+                        account_key: reader.array::<32>()?,
+                        writable_indexes: reader.compact_bytes()?,
+                        readonly_indexes: reader.compact_bytes()?,
+                    })
+                })
+                .collect::<WalletUtilsResult<Vec<AddressTableLookup>>>()?
+        };
+
+        Ok(Self {
+            version,
+            header,
+            account_keys,
+            recent_blockhash,
+            instructions,
+            address_table_lookups,
+        })
+    }
+
+    /// Encode the message into the wire format. These are the bytes that are signed
+    pub fn encode(&self) -> WalletUtilsResult<Vec<u8>> {
+        let mut bytes = Vec::<u8>::new();
+
+        match self.version {
+            TransactionVersion::Legacy => {
+                if !self.address_table_lookups.is_empty() {
+                    return Err(WalletUtilsError::LegacyMessageWithAddressTableLookups);
+                }
+            }
+            TransactionVersion::Number(0) => bytes.push(VERSION_PREFIX_MASK),
+            version => {
+                return Err(WalletUtilsError::TransactionVersionNotSupported(
+                    version.to_string(),
+                ))
+            }
+        }
+
+        bytes.extend_from_slice(&[
+            self.header.num_required_signatures,
+            self.header.num_readonly_signed_accounts,
+            self.header.num_readonly_unsigned_accounts,
+        ]);
+
+        encode_compact_u16(self.account_keys.len(), &mut bytes)?;
+        self.account_keys
+            .iter()
+            .for_each(|account_key| bytes.extend_from_slice(account_key));
+
+        bytes.extend_from_slice(&self.recent_blockhash);
+
+        encode_compact_u16(self.instructions.len(), &mut bytes)?;
+        self.instructions.iter().try_for_each(|instruction| {
+            bytes.push(instruction.program_id_index);
+            encode_compact_bytes(&instruction.accounts, &mut bytes)?;
+            encode_compact_bytes(&instruction.data, &mut bytes)
+        })?;
+
+        if self.version != TransactionVersion::Legacy {
+            encode_compact_u16(self.address_table_lookups.len(), &mut bytes)?;
+            self.address_table_lookups.iter().try_for_each(|lookup| {
+                bytes.extend_from_slice(&lookup.account_key);
+                encode_compact_bytes(&lookup.writable_indexes, &mut bytes)?;
+                encode_compact_bytes(&lookup.readonly_indexes, &mut bytes)
+            })?;
+        }
+
+        Ok(bytes)
+    }
+
+    /// The account keys that are required to sign the message
+    pub fn signer_keys(&self) -> &[[u8; 32]] {
+        let signers = (self.header.num_required_signatures as usize).min(self.account_keys.len());
+
+        &self.account_keys[..signers]
+    }
+}
+
+/// The header of a [Message] describing which of the static account keys
+/// are signers and which are read-only
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct MessageHeader {
+    /// The number of signatures required, the first account keys are the signers
+    pub num_required_signatures: u8,
+    /// The number of the signer account keys that are read-only
+    pub num_readonly_signed_accounts: u8,
+    /// The number of the non-signer account keys that are read-only
+    pub num_readonly_unsigned_accounts: u8,
+}
+
+/// An instruction of a [Message] referencing the account keys by index
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct CompiledInstruction {
+    /// The index of the program account key
+    pub program_id_index: u8,
+    /// The indexes of the accounts passed to the program
+    pub accounts: Vec<u8>,
+    /// The input data of the program
+    pub data: Vec<u8>,
+}
+
+/// Accounts loaded from an address lookup table by a version zero [Message]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct AddressTableLookup {
+    /// The address of the lookup table
+    pub account_key: [u8; 32],
+    /// The indexes of the writable accounts in the lookup table
+    pub writable_indexes: Vec<u8>,
+    /// The indexes of the read-only accounts in the lookup table
+    pub readonly_indexes: Vec<u8>,
+}
+
+/// The bit set in the first byte of a versioned message
+pub(crate) const VERSION_PREFIX_MASK: u8 = 0x80;
+
+/// Decode a `compact-u16` length returning the length and the number of bytes it occupied.
+/// Only the shortest encoding of a length is accepted
+pub fn decode_compact_u16(bytes: &[u8]) -> WalletUtilsResult<(usize, usize)> {
+    let mut value = 0usize;
+
+    for (index, byte) in bytes.iter().take(3).enumerate() {
+        value |= ((byte & 0x7f) as usize) << (index * 7);
+
+        if byte & 0x80 == 0 {
+            // A trailing zero byte is an alias of the shorter encoding
+            if (index > 0 && *byte == 0) || value > u16::MAX as usize {
+                return Err(WalletUtilsError::InvalidTransactionBytes);
+            }
+
+            return Ok((value, index + 1));
+        }
+    }
+
+    Err(WalletUtilsError::InvalidTransactionBytes)
+}
+
+/// Encode a length as a `compact-u16` appending it to the `bytes`
+pub fn encode_compact_u16(value: usize, bytes: &mut Vec<u8>) -> WalletUtilsResult<()> {
+    let mut value =
+        u16::try_from(value).or(Err(WalletUtilsError::InvalidTransactionBytes))? as usize;
+
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+
+        if value == 0 {
+            bytes.push(byte);
+
+            return Ok(());
+        }
+
+        bytes.push(byte | 0x80);
+    }
+}
+
+fn encode_compact_bytes(value: &[u8], bytes: &mut Vec<u8>) -> WalletUtilsResult<()> {
+    encode_compact_u16(value.len(), bytes)?;
+    bytes.extend_from_slice(value);
+
+    Ok(())
+}
+
+struct WireReader<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> WireReader<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, offset: 0 }
+    }
+
+    fn take(&mut self, len: usize) -> WalletUtilsResult<&'a [u8]> {
+        let end = self
+            .offset
+            .checked_add(len)
+            .filter(|end| *end <= self.bytes.len())
+            .ok_or(WalletUtilsError::InvalidTransactionBytes)?;
+
+        let taken = &self.bytes[self.offset..end];
+        self.offset = end;
+
+        Ok(taken)
+    }
+
+    fn peek(&self) -> WalletUtilsResult<u8> {
+        self.bytes
+            .get(self.offset)
+            .copied()
+            .ok_or(WalletUtilsError::InvalidTransactionBytes)
+    }
+
+    fn u8(&mut self) -> WalletUtilsResult<u8> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn array<const N: usize>(&mut self) -> WalletUtilsResult<[u8; N]> {
+        self.take(N)?
+            .try_into()
+            .or(Err(WalletUtilsError::InvalidTransactionBytes))
+    }
+
+    fn compact_u16(&mut self) -> WalletUtilsResult<usize> {
+        let (value, len) = decode_compact_u16(&self.bytes[self.offset..])?;
+        self.offset += len;
+
+        Ok(value)
+    }
+
+    fn compact_bytes(&mut self) -> WalletUtilsResult<Vec<u8>> {
+        let len = self.compact_u16()?;
+
+        Ok(self.take(len)?.to_vec())
+    }
+
+    fn finish(&self) -> WalletUtilsResult<()> {
+        if self.offset == self.bytes.len() {
+            Ok(())
+        } else {
+            Err(WalletUtilsError::InvalidTransactionBytes)
+        }
+    }
+}
+
+#[cfg(test)]
+mod transaction_tests {
+    use super::*;
+
+    fn transfer_message(version: TransactionVersion) -> Message {
+        Message {
+            version,
+            header: MessageHeader {
+                num_required_signatures: 1,
+                num_readonly_signed_accounts: 0,
+                num_readonly_unsigned_accounts: 1,
+            },
+            account_keys: vec![[1u8; 32], [2u8; 32], [0u8; 32]],
+            recent_blockhash: [9u8; 32],
+            instructions: vec![CompiledInstruction {
+                program_id_index: 2,
+                accounts: vec![0, 1],
+                data: vec![2, 0, 0, 0, 0, 202, 154, 59, 0, 0, 0, 0],
+            }],
+            address_table_lookups: Vec::default(),
+        }
+    }
+
+    #[test]
+    fn legacy_roundtrip() {
+        let transaction = Transaction {
+            signatures: vec![[0u8; 64]],
+            message: transfer_message(TransactionVersion::Legacy),
+        };
+
+        let bytes = transaction.encode().unwrap();
+        assert_eq!(bytes[0], 1);
+        assert_eq!(&bytes[65..68], &[1, 0, 1]);
+        assert_eq!(
+            TransactionVersion::from_transaction_bytes(&bytes),
+            Ok(TransactionVersion::Legacy)
+        );
+        assert_eq!(
+            Transaction::message_bytes(&bytes).unwrap(),
+            transaction.message.encode().unwrap()
+        );
+        assert_eq!(Transaction::decode(&bytes), Ok(transaction));
+    }
+
+    #[test]
+    fn version_zero_roundtrip() {
+        let mut message = transfer_message(TransactionVersion::V0);
+        message.address_table_lookups.push(AddressTableLookup {
+            account_key: [5u8; 32],
+            writable_indexes: vec![0, 3],
+            readonly_indexes: vec![1],
+        });
+        let transaction = Transaction {
+            signatures: vec![[4u8; 64]],
+            message,
+        };
+
+        let bytes = transaction.encode().unwrap();
+        assert_eq!(bytes[65], VERSION_PREFIX_MASK);
+        assert_eq!(Transaction::decode(&bytes), Ok(transaction.clone()));
+        assert_eq!(
+            transaction.signers().collect::<Vec<_>>(),
+            vec![(&[1u8; 32], &[4u8; 64])]
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_bytes() {
+        let bytes = Transaction {
+            signatures: vec![[0u8; 64]],
+            message: transfer_message(TransactionVersion::Legacy),
+        }
+        .encode()
+        .unwrap();
+
+        assert_eq!(
+            Transaction::decode(&bytes[..bytes.len() - 1]),
+            Err(WalletUtilsError::InvalidTransactionBytes)
+        );
+        assert_eq!(
+            Transaction::decode(&[bytes.as_slice(), &[0]].concat()),
+            Err(WalletUtilsError::InvalidTransactionBytes)
+        );
+
+        let unsigned = Transaction {
+            signatures: Vec::default(),
+            message: transfer_message(TransactionVersion::Legacy),
+        }
+        .encode()
+        .unwrap();
+        assert_eq!(
+            Transaction::decode(&unsigned),
+            Err(WalletUtilsError::InvalidTransactionBytes)
+        );
+
+        let mut legacy_with_lookups = transfer_message(TransactionVersion::Legacy);
+        legacy_with_lookups
+            .address_table_lookups
+            .push(AddressTableLookup::default());
+        assert_eq!(
+            legacy_with_lookups.encode(),
+            Err(WalletUtilsError::LegacyMessageWithAddressTableLookups)
+        );
+    }
+
+    #[test]
+    fn compact_u16() {
+        [
+            (0usize, vec![0u8]),
+            (127, vec![0x7f]),
+            (128, vec![0x80, 0x01]),
+            (16384, vec![0x80, 0x80, 0x01]),
+        ]
+        .into_iter()
+        .for_each(|(value, encoded)| {
+            let mut bytes = Vec::new();
+            encode_compact_u16(value, &mut bytes).unwrap();
+            assert_eq!(bytes, encoded);
+            assert_eq!(decode_compact_u16(&bytes), Ok((value, encoded.len())));
+        });
+
+        [
+            vec![0x80, 0x00],
+            vec![0x80, 0x80, 0x00],
+            vec![0xff, 0xff, 0x04],
+        ]
+        .into_iter()
+        .for_each(|bytes| {
+            assert_eq!(
+                decode_compact_u16(&bytes),
+                Err(WalletUtilsError::InvalidTransactionBytes)
+            );
+        });
+    }
+}
@@ -0,0 +1,114 @@
+use crate::{
+    transaction::{decode_compact_u16, VERSION_PREFIX_MASK},
+    WalletUtilsError, WalletUtilsResult,
+};
+
+/// The version of a Solana transaction as listed in the `supportedTransactionVersions`
+/// of the `solana:signTransaction` and `solana:signAndSendTransaction` features
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum TransactionVersion {
+    /// A legacy transaction
+    #[default]
+    Legacy,
+    /// A versioned transaction, example `0` for version zero transactions
+    Number(u8),
+}
+
+impl TransactionVersion {
+    /// Version zero transactions, which support address lookup tables
+    pub const V0: Self = Self::Number(0);
+
+    /// The length in bytes of an Ed25519 signature
+    const SIGNATURE_LENGTH: usize = 64;
+
+    /// Parse a version listed by a wallet. `legacy` and numbers are supported.
+    /// Any other value returns [None] so that a wallet listing a version
+    /// unknown to this library can still be used with the versions it supports
+    pub fn parse(value: &str) -> Option<Self> {
+        if value == "legacy" {
+            return Some(Self::Legacy);
+        }
+
+        value.parse::<u8>().ok().and_then(Self::from_number)
+    }
+
+    /// Parse a numbered version, returns [None] if the number
+    /// cannot be encoded in the prefix of a message
+    pub fn from_number(version: u8) -> Option<Self> {
+        (version < VERSION_PREFIX_MASK).then_some(Self::Number(version))
+    }
+
+    /// Read the version of a serialized transaction from the prefix of its message.
+    /// The bytes are expected in the wire format where the message follows the signatures
+    pub fn from_transaction_bytes(transaction_bytes: &[u8]) -> WalletUtilsResult<Self> {
+        let (signatures, offset) = decode_compact_u16(transaction_bytes)?;
+
+        let message_start = signatures
+            .checked_mul(Self::SIGNATURE_LENGTH)
+            .and_then(|signatures_length| signatures_length.checked_add(offset))
+            .ok_or(WalletUtilsError::InvalidTransactionBytes)?;
+
+        let prefix = *transaction_bytes
+            .get(message_start)
+            .ok_or(WalletUtilsError::InvalidTransactionBytes)?;
+
+        if prefix & VERSION_PREFIX_MASK == 0 {
+            Ok(Self::Legacy)
+        } else {
+            Ok(Self::Number(prefix & !VERSION_PREFIX_MASK))
+        }
+    }
+}
+
+impl core::fmt::Display for TransactionVersion {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Legacy => write!(f, "legacy"),
+            Self::Number(version) => write!(f, "{version}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod transaction_version_tests {
+    use super::*;
+
+    #[test]
+    fn parses_listed_versions() {
+        assert_eq!(
+            TransactionVersion::parse("legacy"),
+            Some(TransactionVersion::Legacy)
+        );
+        assert_eq!(TransactionVersion::parse("0"), Some(TransactionVersion::V0));
+        assert_eq!(TransactionVersion::parse("200"), None);
+        assert_eq!(TransactionVersion::parse("v2"), None);
+    }
+
+    #[test]
+    fn reads_message_prefix() {
+        let mut legacy = vec![1u8];
+        legacy.extend_from_slice(&[0u8; 64]);
+        legacy.extend_from_slice(&[1, 0, 1]);
+        assert_eq!(
+            TransactionVersion::from_transaction_bytes(&legacy),
+            Ok(TransactionVersion::Legacy)
+        );
+
+        let mut version_zero = vec![2u8];
+        version_zero.extend_from_slice(&[0u8; 128]);
+        version_zero.extend_from_slice(&[0x80, 2, 0, 1]);
+        assert_eq!(
+            TransactionVersion::from_transaction_bytes(&version_zero),
+            Ok(TransactionVersion::V0)
+        );
+
+        assert_eq!(
+            TransactionVersion::from_transaction_bytes(&[1u8; 64]),
+            Err(WalletUtilsError::InvalidTransactionBytes)
+        );
+        assert_eq!(
+            TransactionVersion::from_transaction_bytes(&[0xff, 0xff, 0xff]),
+            Err(WalletUtilsError::InvalidTransactionBytes)
+        );
+    }
+}
@@ -1,15 +1,24 @@
-use std::borrow::Cow;
+use std::{borrow::Cow, cmp::Ordering};
+
+use crate::{WalletUtilsError, WalletUtilsResult};
 
 /// The Version of the Wallet Standard currently implemented.
 /// This may be used by the app to determine compatibility and feature detect.
 pub const WALLET_STANDARD_VERSION: &str = "1.0.0";
 
-/// Semver Versioning struct
-#[derive(Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// Semver Versioning struct following the [SemVer 2.0.0](https://semver.org) specification
+/// including pre-release and build metadata, e.g. `1.0.0-beta.1+exp.sha.5114f85`.
+///
+/// [Ord] follows SemVer precedence, build metadata is only compared
+/// to break ties so that the ordering agrees with [Eq].
+/// Use [SemverVersion::cmp_precedence] to ignore build metadata.
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
 pub struct SemverVersion {
-    major: u8,
-    minor: u8,
-    patch: u8,
+    major: u64,
+    minor: u64,
+    patch: u64,
+    pre_release: Vec<PreReleaseIdentifier>,
+    build: Vec<String>,
 }
 
 impl SemverVersion {
@@ -18,64 +27,629 @@ impl SemverVersion {
         Self::default()
     }
 
+    /// Parse a SemVer version like `1.2.3`, `1.0.0-beta.1` or `1.0.0+build.5`
+    pub fn parse(version: &str) -> WalletUtilsResult<Self> {
+        let invalid = || WalletUtilsError::InvalidSemverVersion(version.to_string());
+
+        let (version_and_pre_release, build) = match version.split_once('+') {
+            Some((left, build)) => (left, Some(build)),
+            None => (version, None),
+        };
+        let (core, pre_release) = match version_and_pre_release.split_once('-') {
+            Some((core, pre_release)) => (core, Some(pre_release)),
+            None => (version_and_pre_release, None),
+        };
+
+        let numbers = core
+            .split('.')
+            .map(|number| Self::parse_number(number).ok_or_else(invalid))
+            .collect::<WalletUtilsResult<Vec<u64>>>()?;
+        let [major, minor, patch] = numbers.as_slice() else {
+            return Err(invalid());
+        };
+
+        let pre_release = pre_release
+            .map(|pre_release| {
+                pre_release
+                    .split('.')
+                    .map(|identifier| PreReleaseIdentifier::parse(identifier).ok_or_else(invalid))
+                    .collect::<WalletUtilsResult<Vec<PreReleaseIdentifier>>>()
+            })
+            .transpose()?
+            .unwrap_or_default();
+
+        let build = build
+            .map(|build| {
+                build
+                    .split('.')
+                    .map(|identifier| {
+                        Self::is_identifier(identifier)
+                            .then(|| identifier.to_string())
+                            .ok_or_else(invalid)
+                    })
+                    .collect::<WalletUtilsResult<Vec<String>>>()
+            })
+            .transpose()?
+            .unwrap_or_default();
+
+        Ok(Self {
+            major: *major,
+            minor: *minor,
+            patch: *patch,
+            pre_release,
+            build,
+        })
+    }
+
+    /// The version of the wallet standard implemented by this library
+    pub fn wallet_standard() -> Self {
+        Self::new().set_major(1)
+    }
+
     /// Set the major version
-    pub fn set_major(mut self, major: u8) -> Self {
+    pub fn set_major(mut self, major: u64) -> Self {
         self.major = major;
 
         self
     }
     /// Set the minor version
-    pub fn set_minor(mut self, minor: u8) -> Self {
+    pub fn set_minor(mut self, minor: u64) -> Self {
         self.minor = minor;
 
         self
     }
 
     /// Set the patch version
-    pub fn set_patch(mut self, patch: u8) -> Self {
+    pub fn set_patch(mut self, patch: u64) -> Self {
         self.patch = patch;
 
         self
     }
 
+    /// Set the pre-release identifiers
+    pub fn set_pre_release(mut self, pre_release: Vec<PreReleaseIdentifier>) -> Self {
+        self.pre_release = pre_release;
+
+        self
+    }
+
+    /// Set the build metadata identifiers
+    pub fn set_build(mut self, build: Vec<String>) -> Self {
+        self.build = build;
+
+        self
+    }
+
     /// The major version
-    pub fn major(&self) -> u8 {
+    pub fn major(&self) -> u64 {
         self.major
     }
 
     /// The minor version
-    pub fn minor(&self) -> u8 {
+    pub fn minor(&self) -> u64 {
         self.minor
     }
 
     /// The patch version
-    pub fn patch(&self) -> u8 {
+    pub fn patch(&self) -> u64 {
         self.patch
     }
 
+    /// The pre-release identifiers, empty for a release version
+    pub fn pre_release(&self) -> &[PreReleaseIdentifier] {
+        &self.pre_release
+    }
+
+    /// The build metadata identifiers
+    pub fn build(&self) -> &[String] {
+        &self.build
+    }
+
+    /// Whether this is a pre-release version
+    pub fn is_pre_release(&self) -> bool {
+        !self.pre_release.is_empty()
+    }
+
+    /// Compare the SemVer precedence of two versions, ignoring build metadata
+    pub fn cmp_precedence(&self, other: &Self) -> Ordering {
+        (self.major, self.minor, self.patch)
+            .cmp(&(other.major, other.minor, other.patch))
+            .then_with(|| {
+                // A pre-release version has a lower precedence than the release version
+                match (self.pre_release.is_empty(), other.pre_release.is_empty()) {
+                    (true, true) => Ordering::Equal,
+                    (true, false) => Ordering::Greater,
+                    (false, true) => Ordering::Less,
+                    (false, false) => self.pre_release.cmp(&other.pre_release),
+                }
+            })
+    }
+
+    /// Check whether the version satisfies the requirement
+    pub fn satisfies(&self, requirement: &SemverRequirement) -> bool {
+        requirement.matches(self)
+    }
+
     /// Get the string version of [Self] in the format `major.minor.patch`
+    /// followed by the pre-release and build metadata if any
     pub fn stringify_version<'a>(&'a self) -> Cow<'a, str> {
-        Cow::Borrowed("")
-            + Cow::Owned(self.major.to_string())
-            + "."
-            + Cow::Owned(self.minor.to_string())
-            + "."
-            + Cow::Owned(self.minor.to_string())
+        Cow::Owned(self.to_string())
+    }
+
+    fn parse_number(number: &str) -> Option<u64> {
+        let valid = !number.is_empty()
+            && number.bytes().all(|byte| byte.is_ascii_digit())
+            && (number == "0" || !number.starts_with('0'));
+
+        valid.then(|| number.parse::<u64>().ok()).flatten()
+    }
+
+    fn is_identifier(identifier: &str) -> bool {
+        !identifier.is_empty()
+            && identifier
+                .bytes()
+                .all(|byte| byte.is_ascii_alphanumeric() || byte == b'-')
+    }
+}
+
+impl PartialOrd for SemverVersion {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for SemverVersion {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.cmp_precedence(other)
+            .then_with(|| self.build.cmp(&other.build))
+    }
+}
+
+impl core::str::FromStr for SemverVersion {
+    type Err = WalletUtilsError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
     }
 }
 
 impl core::fmt::Debug for SemverVersion {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "SemverVersion({}.{}.{})",
-            self.major, self.minor, self.patch
-        )
+        write!(f, "SemverVersion({self})")
     }
 }
 
 impl core::fmt::Display for SemverVersion {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)?;
+
+        if !self.pre_release.is_empty() {
+            let pre_release = self
+                .pre_release
+                .iter()
+                .map(|identifier| identifier.to_string())
+                .collect::<Vec<String>>();
+            write!(f, "-{}", pre_release.join("."))?;
+        }
+
+        if !self.build.is_empty() {
+            write!(f, "+{}", self.build.join("."))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// An identifier of the pre-release part of a [SemverVersion], e.g. `beta` or `1` in `1.0.0-beta.1`.
+/// Numeric identifiers have a lower precedence than alphanumeric ones
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PreReleaseIdentifier {
+    /// An identifier made up of only digits, compared numerically
+    Numeric(u64),
+    /// An identifier containing letters or hyphens, compared lexically in ASCII sort order
+    AlphaNumeric(String),
+}
+
+impl PreReleaseIdentifier {
+    /// Parse a single pre-release identifier
+    pub fn parse(identifier: &str) -> Option<Self> {
+        if !SemverVersion::is_identifier(identifier) {
+            return None;
+        }
+
+        if identifier.bytes().all(|byte| byte.is_ascii_digit()) {
+            SemverVersion::parse_number(identifier).map(Self::Numeric)
+        } else {
+            Some(Self::AlphaNumeric(identifier.to_string()))
+        }
+    }
+}
+
+impl core::fmt::Display for PreReleaseIdentifier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Numeric(value) => write!(f, "{value}"),
+            Self::AlphaNumeric(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+/// The operator of a [SemverComparator]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum SemverOp {
+    /// `=1.2.3`, exactly the version. A partial version like `=1.2` matches any `1.2.x`
+    Exact,
+    /// `>1.2.3`
+    Greater,
+    /// `>=1.2.3`
+    GreaterEq,
+    /// `<1.2.3`
+    Less,
+    /// `<=1.2.3`
+    LessEq,
+    /// `~1.2.3`, only patch updates are compatible
+    Tilde,
+    /// `^1.2.3`, updates that do not modify the left-most non-zero number are compatible.
+    /// This is the default when a comparator has no operator
+    #[default]
+    Caret,
+}
+
+/// A single comparison in a [SemverRequirement] like `^1.2` or `>=1.0.0-beta`
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct SemverComparator {
+    op: SemverOp,
+    major: u64,
+    minor: Option<u64>,
+    patch: Option<u64>,
+    pre_release: Vec<PreReleaseIdentifier>,
+}
+
+impl SemverComparator {
+    /// Parse a comparator like `^1.2.3`, `~1.2`, `>=1.0.0` or `1`
+    pub fn parse(comparator: &str) -> WalletUtilsResult<Self> {
+        let invalid = || WalletUtilsError::InvalidSemverRequirement(comparator.to_string());
+        let trimmed = comparator.trim();
+
+        let (op, version) = [
+            (">=", SemverOp::GreaterEq),
+            ("<=", SemverOp::LessEq),
+            (">", SemverOp::Greater),
+            ("<", SemverOp::Less),
+            ("=", SemverOp::Exact),
+            ("~", SemverOp::Tilde),
+            ("^", SemverOp::Caret),
+        ]
+        .iter()
+        .find_map(|(prefix, op)| trimmed.strip_prefix(prefix).map(|version| (*op, version)))
+        .unwrap_or((SemverOp::Caret, trimmed));
+        let version = version.trim();
+
+        let (core, pre_release) = match version.split_once('-') {
+            Some((core, pre_release)) => (core, Some(pre_release)),
+            None => (version, None),
+        };
+
+        let numbers = core
+            .split('.')
+            .map(|number| SemverVersion::parse_number(number).ok_or_else(invalid))
+            .collect::<WalletUtilsResult<Vec<u64>>>()?;
+        let (major, minor, patch) = match numbers.as_slice() {
+            [major] => (*major, None, None),
+            [major, minor] => (*major, Some(*minor), None),
+            [major, minor, patch] => (*major, Some(*minor), Some(*patch)),
+            _ => return Err(invalid()),
+        };
+
+        let pre_release = match pre_release {
+            Some(pre_release) if patch.is_some() => pre_release
+                .split('.')
+                .map(|identifier| PreReleaseIdentifier::parse(identifier).ok_or_else(invalid))
+                .collect::<WalletUtilsResult<Vec<PreReleaseIdentifier>>>()?,
+            Some(_) => return Err(invalid()),
+            None => Vec::default(),
+        };
+
+        Ok(Self {
+            op,
+            major,
+            minor,
+            patch,
+            pre_release,
+        })
+    }
+
+    /// The operator
+    pub fn op(&self) -> SemverOp {
+        self.op
+    }
+
+    /// Check whether the version matches this comparator, ignoring the pre-release rules of [SemverRequirement]
+    pub fn matches(&self, version: &SemverVersion) -> bool {
+        let lower = || {
+            SemverVersion::new()
+                .set_major(self.major)
+                .set_minor(self.minor.unwrap_or_default())
+                .set_patch(self.patch.unwrap_or_default())
+                .set_pre_release(self.pre_release.clone())
+        };
+        let at_least = |minimum: SemverVersion| version.cmp_precedence(&minimum).is_ge();
+
+        match (self.op, self.minor, self.patch) {
+            (SemverOp::Exact, Some(_), Some(_)) => version.cmp_precedence(&lower()).is_eq(),
+            (SemverOp::Exact | SemverOp::Tilde, Some(minor), None) => {
+                version.major == self.major && version.minor == minor
+            }
+            (SemverOp::Exact | SemverOp::Tilde | SemverOp::Caret, None, _) => {
+                version.major == self.major
+            }
+            (SemverOp::Greater, Some(_), Some(_)) => version.cmp_precedence(&lower()).is_gt(),
+            (SemverOp::Greater, Some(minor), None) => {
+                (version.major, version.minor) > (self.major, minor)
+            }
+            (SemverOp::Greater, None, _) => version.major > self.major,
+            (SemverOp::GreaterEq, _, _) => at_least(lower()),
+            (SemverOp::Less, _, _) => version.cmp_precedence(&lower()).is_lt(),
+            (SemverOp::LessEq, Some(_), Some(_)) => version.cmp_precedence(&lower()).is_le(),
+            (SemverOp::LessEq, Some(minor), None) => {
+                (version.major, version.minor) <= (self.major, minor)
+            }
+            (SemverOp::LessEq, None, _) => version.major <= self.major,
+            (SemverOp::Tilde, Some(minor), Some(_)) => {
+                at_least(lower()) && version.major == self.major && version.minor == minor
+            }
+            (SemverOp::Caret, Some(minor), patch) => {
+                let compatible = if self.major > 0 {
+                    version.major == self.major
+                } else if minor > 0 || patch.is_none() {
+                    version.major == 0 && version.minor == minor
+                } else {
+                    version.major == 0 && version.minor == 0 && Some(version.patch) == patch
+                };
+
+                compatible && at_least(lower())
+            }
+        }
+    }
+
+    /// Whether a pre-release `version` is allowed to match, which is only
+    /// the case if this comparator has a pre-release on the same `major.minor.patch`
+    fn allows_pre_release(&self, version: &SemverVersion) -> bool {
+        !self.pre_release.is_empty()
+            && self.major == version.major
+            && self.minor == Some(version.minor)
+            && self.patch == Some(version.patch)
+    }
+}
+
+impl core::fmt::Display for SemverComparator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let op = match self.op {
+            SemverOp::Exact => "=",
+            SemverOp::Greater => ">",
+            SemverOp::GreaterEq => ">=",
+            SemverOp::Less => "<",
+            SemverOp::LessEq => "<=",
+            SemverOp::Tilde => "~",
+            SemverOp::Caret => "^",
+        };
+        write!(f, "{op}{}", self.major)?;
+
+        if let Some(minor) = self.minor {
+            write!(f, ".{minor}")?;
+        }
+        if let Some(patch) = self.patch {
+            write!(f, ".{patch}")?;
+        }
+        if !self.pre_release.is_empty() {
+            let pre_release = self
+                .pre_release
+                .iter()
+                .map(|identifier| identifier.to_string())
+                .collect::<Vec<String>>();
+            write!(f, "-{}", pre_release.join("."))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A version requirement made up of comma separated [comparators](SemverComparator)
+/// which must all match, e.g. `^1.0`, `~1.2.3` or `>=1.0.0, <2.0.0`. `*` matches any release version.
+///
+/// Like Cargo, a pre-release version only matches if one of the comparators
+/// has a pre-release on the same `major.minor.patch`
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct SemverRequirement {
+    comparators: Vec<SemverComparator>,
+}
+
+impl SemverRequirement {
+    /// Parse a version requirement
+    pub fn parse(requirement: &str) -> WalletUtilsResult<Self> {
+        if requirement.trim() == "*" {
+            return Ok(Self::default());
+        }
+
+        let comparators = requirement
+            .split(',')
+            .map(SemverComparator::parse)
+            .collect::<WalletUtilsResult<Vec<SemverComparator>>>()?;
+
+        Ok(Self { comparators })
+    }
+
+    /// The requirement compatible with the wallet standard version implemented by this library,
+    /// [WALLET_STANDARD_VERSION] using a caret requirement
+    pub fn wallet_standard() -> Self {
+        Self {
+            comparators: vec![SemverComparator {
+                op: SemverOp::Caret,
+                major: 1,
+                minor: Some(0),
+                patch: Some(0),
+                pre_release: Vec::default(),
+            }],
+        }
+    }
+
+    /// The comparators
+    pub fn comparators(&self) -> &[SemverComparator] {
+        &self.comparators
+    }
+
+    /// Check whether the version matches all the comparators
+    pub fn matches(&self, version: &SemverVersion) -> bool {
+        let pre_release_allowed = !version.is_pre_release()
+            || self
+                .comparators
+                .iter()
+                .any(|comparator| comparator.allows_pre_release(version));
+
+        pre_release_allowed
+            && self
+                .comparators
+                .iter()
+                .all(|comparator| comparator.matches(version))
+    }
+}
+
+impl core::str::FromStr for SemverRequirement {
+    type Err = WalletUtilsError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl core::fmt::Display for SemverRequirement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.comparators.is_empty() {
+            return write!(f, "*");
+        }
+
+        let comparators = self
+            .comparators
+            .iter()
+            .map(|comparator| comparator.to_string())
+            .collect::<Vec<String>>();
+
+        write!(f, "{}", comparators.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod version_tests {
+    use super::*;
+
+    fn version(value: &str) -> SemverVersion {
+        SemverVersion::parse(value).unwrap()
+    }
+
+    fn matches(requirement: &str, value: &str) -> bool {
+        SemverRequirement::parse(requirement)
+            .unwrap()
+            .matches(&version(value))
+    }
+
+    #[test]
+    fn parses_and_displays() {
+        let parsed = version("1.0.0-beta.11+exp.sha.5114f85");
+        assert_eq!(parsed.major(), 1);
+        assert_eq!(
+            parsed.pre_release(),
+            &[
+                PreReleaseIdentifier::AlphaNumeric("beta".to_string()),
+                PreReleaseIdentifier::Numeric(11)
+            ]
+        );
+        assert_eq!(parsed.build(), &["exp", "sha", "5114f85"]);
+        assert_eq!(parsed.to_string(), "1.0.0-beta.11+exp.sha.5114f85");
+        assert_eq!(version("1.2.3").stringify_version(), "1.2.3");
+        assert_eq!(version("300.0.0").major(), 300);
+        assert_eq!(
+            SemverVersion::parse(WALLET_STANDARD_VERSION),
+            Ok(SemverVersion::wallet_standard())
+        );
+
+        [
+            "1.0",
+            "1.0.0.0",
+            "01.0.0",
+            "1.0.0-",
+            "1.0.0-01",
+            "1.0.0+",
+            "1.0.0-b@d",
+            "v1.0.0",
+        ]
+        .iter()
+        .for_each(|invalid| {
+            assert_eq!(
+                SemverVersion::parse(invalid),
+                Err(WalletUtilsError::InvalidSemverVersion(invalid.to_string()))
+            )
+        });
+    }
+
+    #[test]
+    fn precedence() {
+        let ordered = [
+            "1.0.0-alpha",
+            "1.0.0-alpha.1",
+            "1.0.0-alpha.beta",
+            "1.0.0-beta",
+            "1.0.0-beta.2",
+            "1.0.0-beta.11",
+            "1.0.0-rc.1",
+            "1.0.0",
+            "1.0.1",
+            "1.1.0",
+            "2.0.0",
+        ]
+        .map(version);
+
+        ordered
+            .windows(2)
+            .for_each(|pair| assert!(pair[0] < pair[1], "{} < {}", pair[0], pair[1]));
+
+        assert!(version("1.0.0+a")
+            .cmp_precedence(&version("1.0.0+b"))
+            .is_eq());
+        assert_ne!(version("1.0.0+a"), version("1.0.0+b"));
+    }
+
+    #[test]
+    fn requirements() {
+        assert!(matches("^1.2.3", "1.9.0"));
+        assert!(!matches("^1.2.3", "1.2.2"));
+        assert!(!matches("^1.2.3", "2.0.0"));
+        assert!(matches("^0.2.3", "0.2.9"));
+        assert!(!matches("^0.2.3", "0.3.0"));
+        assert!(!matches("^0.0.3", "0.0.4"));
+        assert!(matches("1", "1.5.0"));
+
+        assert!(matches("~1.2.3", "1.2.9"));
+        assert!(!matches("~1.2.3", "1.3.0"));
+        assert!(matches("~1", "1.9.9"));
+
+        assert!(matches(">=1.0.0, <2.0.0", "1.4.0"));
+        assert!(!matches(">=1.0.0, <2.0.0", "2.0.0"));
+        assert!(matches(">1.2", "1.3.0"));
+        assert!(!matches(">1.2", "1.2.9"));
+        assert!(matches("<=1.2", "1.2.9"));
+        assert!(matches("=1.2", "1.2.7"));
+        assert!(matches("*", "3.0.0"));
+
+        assert!(!matches("^1.0.0", "1.1.0-beta"));
+        assert!(matches(">=1.1.0-alpha", "1.1.0-beta"));
+        assert!(!matches(">=1.1.0-alpha", "1.2.0-beta"));
+
+        assert!(SemverRequirement::wallet_standard().matches(&version("1.3.0")));
+        assert!(!SemverRequirement::wallet_standard().matches(&version("2.0.0")));
+        assert_eq!(
+            SemverRequirement::parse(">=1.0.0, <2").unwrap().to_string(),
+            ">=1.0.0, <2"
+        );
+        assert!(SemverRequirement::parse("^1.x").is_err());
     }
 }
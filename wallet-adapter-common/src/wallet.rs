@@ -1,8 +1,10 @@
 use std::borrow::Cow;
 
 use crate::{
-    chains::ChainSupport, clusters::Cluster, feature_support::FeatureSupport, SemverVersion,
-    WalletAccountData,
+    chains::{ChainId, ChainSupport},
+    clusters::Cluster,
+    feature_support::FeatureSupport,
+    SemverRequirement, SemverVersion, WalletAccountData,
 };
 
 /// Wallet information without any browser function calls for `wallet-adapter` standard operations
@@ -13,6 +15,10 @@ pub struct WalletData {
     icon: Option<Cow<'static, str>>,
     accounts: Vec<WalletAccountData>,
     chains: Vec<Cluster>,
+    // All the chains advertised by the wallet in the CAIP-2 format, of any namespace
+    chain_ids: Vec<ChainId>,
+    // Chains advertised by the wallet that are not a known [Cluster], kept as is
+    unrecognized_chains: Vec<String>,
     // Convenience field, instead of going through the `features` field
     supported_features: FeatureSupport,
     // Convenience field, instead of iteration through the `chains` field
@@ -89,6 +95,20 @@ impl WalletData {
         self
     }
 
+    /// Replace the [chain identifiers](ChainId) advertised by the wallet
+    pub fn replace_chain_ids(mut self, chain_ids: Vec<ChainId>) -> Self {
+        self.chain_ids = chain_ids;
+
+        self
+    }
+
+    /// Replace the chains advertised by the wallet that could not be parsed into a [Cluster]
+    pub fn replace_unrecognized_chains(mut self, chains: Vec<String>) -> Self {
+        self.unrecognized_chains = chains;
+
+        self
+    }
+
     /// Set the supported wallet [features](FeatureSupport)
     pub fn set_supported_features(mut self, supported_features: FeatureSupport) -> Self {
         self.supported_features = supported_features;
@@ -113,6 +133,21 @@ impl WalletData {
         &self.chains
     }
 
+    /// Get all the [chain identifiers](ChainId) advertised by the wallet
+    pub fn chain_ids(&self) -> &[ChainId] {
+        &self.chain_ids
+    }
+
+    /// Check whether the wallet advertises the chain
+    pub fn supports_chain(&self, chain_id: &ChainId) -> bool {
+        self.chain_ids.contains(chain_id)
+    }
+
+    /// Get the chains advertised by the wallet that could not be parsed into a [Cluster]
+    pub fn unrecognized_chains(&self) -> &[String] {
+        &self.unrecognized_chains
+    }
+
     /// Check whether the wallet supports mainnet cluster
     pub fn mainnet(&self) -> bool {
         self.supported_chains.mainnet
@@ -163,6 +198,11 @@ impl WalletData {
         self.supported_features.sign_and_send_tx
     }
 
+    /// Check whether the wallet supports `solana:signAndSendAllTransactions` feature
+    pub fn solana_sign_and_send_all_transactions(&self) -> bool {
+        self.supported_features.sign_and_send_all_tx
+    }
+
     /// Check whether the wallet supports `solana:signTransaction` feature
     pub fn solana_sign_transaction(&self) -> bool {
         self.supported_features.sign_tx
@@ -173,6 +213,16 @@ impl WalletData {
         self.icon.as_ref()
     }
 
+    /// Get the features supported by the wallet
+    pub fn supported_features(&self) -> &FeatureSupport {
+        &self.supported_features
+    }
+
+    /// Get the Solana clusters supported by the wallet
+    pub fn supported_chains(&self) -> &ChainSupport {
+        &self.supported_chains
+    }
+
     /// Get the name of the wallet
     pub fn name(&self) -> &str {
         &self.name
@@ -182,6 +232,13 @@ impl WalletData {
     pub fn version(&self) -> &SemverVersion {
         &self.version
     }
+
+    /// Check whether the wallet standard version of the wallet is compatible
+    /// with [WALLET_STANDARD_VERSION](crate::WALLET_STANDARD_VERSION)
+    pub fn supports_wallet_standard(&self) -> bool {
+        self.version
+            .satisfies(&SemverRequirement::wallet_standard())
+    }
 }
 
 impl core::fmt::Debug for WalletData {
@@ -198,6 +255,7 @@ impl core::fmt::Debug for WalletData {
             .field("icon", &self.icon)
             .field("accounts", &self.accounts)
             .field("chains", &chains)
+            .field("unrecognized_chains", &self.unrecognized_chains)
             .finish()
     }
 }
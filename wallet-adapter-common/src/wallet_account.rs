@@ -1,8 +1,9 @@
 use std::borrow::Cow;
 
 use crate::{
-    chains::ChainSupport, feature_support::FeatureSupport, WalletCommonUtils, WalletUtilsError,
-    WalletUtilsResult,
+    chains::{ChainId, ChainSupport},
+    feature_support::FeatureSupport,
+    WalletCommonUtils, WalletUtilsError, WalletUtilsResult,
 };
 
 /// A data URI containing a base64-encoded SVG, WebP, PNG, or GIF image.
@@ -48,6 +49,21 @@ impl WalletAccountData {
         self.chains.as_slice()
     }
 
+    /// The chains supported by the account that are in the CAIP-2 `namespace:reference` format
+    pub fn chain_ids(&self) -> Vec<ChainId> {
+        self.chains
+            .iter()
+            .filter_map(|chain| ChainId::parse(chain).ok())
+            .collect()
+    }
+
+    /// Checks if the account supports the chain
+    pub fn supports_chain(&self, chain_id: &ChainId) -> bool {
+        self.chains
+            .iter()
+            .any(|chain| ChainId::parse(chain).is_ok_and(|parsed| &parsed == chain_id))
+    }
+
     /// Feature names supported by the account.
     /// This must be a subset of the names of {@link Wallet.features | features} of the Wallet.
     pub fn features(&self) -> &[String] {
@@ -147,8 +163,38 @@ impl WalletAccountData {
         self.supported_features.sign_and_send_tx
     }
 
+    /// Checks if `solana:signAndSendAllTransactions` is supported
+    pub fn solana_sign_and_send_all_transactions(&self) -> bool {
+        self.supported_features.sign_and_send_all_tx
+    }
+
     /// Checks if `solana:signTransaction` is supported
     pub fn solana_sign_transaction(&self) -> bool {
         self.supported_features.sign_tx
     }
 }
+
+#[cfg(test)]
+mod wallet_account_tests {
+    use super::*;
+
+    #[test]
+    fn derives_chain_ids_from_chains() {
+        let account = WalletAccountData {
+            chains: ["solana:devnet", "eip155:1", "not a chain"]
+                .map(String::from)
+                .to_vec(),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            account.chain_ids(),
+            vec![
+                ChainId::parse("solana:devnet").unwrap(),
+                ChainId::parse("eip155:1").unwrap()
+            ]
+        );
+        assert!(account.supports_chain(&ChainId::parse("eip155:1").unwrap()));
+        assert!(!account.supports_chain(&ChainId::parse("solana:mainnet").unwrap()));
+    }
+}
@@ -0,0 +1,314 @@
+use std::borrow::Cow;
+
+/// The placeholder for the URL of the app in a [KnownWallet::deeplink_template]
+pub const DEEPLINK_URL_PLACEHOLDER: &str = "{url}";
+
+/// The placeholder for the URL of the origin of the app in a [KnownWallet::deeplink_template]
+pub const DEEPLINK_REF_PLACEHOLDER: &str = "{ref}";
+
+/// How ready a wallet is to be used by the app
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum WalletReadiness {
+    /// The wallet registered itself with the app
+    Installed,
+    /// The wallet has not registered but the app can be opened in the wallet
+    /// using its deeplink, example the in-app browser of a mobile wallet
+    Loadable,
+    /// The wallet has not registered and can only be installed
+    NotDetected,
+}
+
+impl WalletReadiness {
+    /// Get the readiness of a wallet that is `installed`.
+    /// A wallet that is not installed is [Self::Loadable] on a `mobile` device if it has a deeplink
+    pub fn new(installed: bool, mobile: bool, known_wallet: Option<&KnownWallet>) -> Self {
+        if installed {
+            Self::Installed
+        } else if mobile
+            && known_wallet.is_some_and(|known_wallet| known_wallet.deeplink_template().is_some())
+        {
+            Self::Loadable
+        } else {
+            Self::NotDetected
+        }
+    }
+}
+
+/// A wallet known to the app even if it is not installed
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct KnownWallet {
+    name: Cow<'static, str>,
+    icon: Option<Cow<'static, str>>,
+    homepage_url: Cow<'static, str>,
+    install_url: Cow<'static, str>,
+    deeplink_template: Option<Cow<'static, str>>,
+}
+
+impl KnownWallet {
+    /// Instantiate a known wallet with its `name`, optional `icon`, `homepage_url` and `install_url`
+    pub const fn new(
+        name: &'static str,
+        icon: Option<&'static str>,
+        homepage_url: &'static str,
+        install_url: &'static str,
+    ) -> Self {
+        Self {
+            name: Cow::Borrowed(name),
+            icon: Self::borrowed_icon(icon),
+            homepage_url: Cow::Borrowed(homepage_url),
+            install_url: Cow::Borrowed(install_url),
+            deeplink_template: Option::None,
+        }
+    }
+
+    /// Instantiate a known wallet that can open the app using the `deeplink_template`.
+    /// See [Self::set_deeplink_template]
+    pub const fn new_with_deeplink(
+        name: &'static str,
+        icon: Option<&'static str>,
+        homepage_url: &'static str,
+        install_url: &'static str,
+        deeplink_template: &'static str,
+    ) -> Self {
+        Self {
+            name: Cow::Borrowed(name),
+            icon: Self::borrowed_icon(icon),
+            homepage_url: Cow::Borrowed(homepage_url),
+            install_url: Cow::Borrowed(install_url),
+            deeplink_template: Some(Cow::Borrowed(deeplink_template)),
+        }
+    }
+
+    const fn borrowed_icon(icon: Option<&'static str>) -> Option<Cow<'static, str>> {
+        match icon {
+            Some(icon) => Some(Cow::Borrowed(icon)),
+            None => Option::None,
+        }
+    }
+
+    /// Set the icon of the wallet without copying it, see [Self::set_icon]
+    pub fn with_icon(mut self, icon: &'static str) -> Self {
+        self.icon.replace(Cow::Borrowed(icon));
+
+        self
+    }
+
+    /// Set the icon of the wallet, a URL or a Base64 URL web format image
+    pub fn set_icon(mut self, icon: &str) -> Self {
+        self.icon.replace(Cow::Owned(icon.to_string()));
+
+        self
+    }
+
+    /// Set the template of the deeplink that opens the app in the wallet, example
+    /// `https://phantom.app/ul/browse/{url}?ref={ref}`. See [DEEPLINK_URL_PLACEHOLDER]
+    /// and [DEEPLINK_REF_PLACEHOLDER]
+    pub fn set_deeplink_template(mut self, deeplink_template: &str) -> Self {
+        self.deeplink_template
+            .replace(Cow::Owned(deeplink_template.to_string()));
+
+        self
+    }
+
+    /// Get the name of the wallet
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Get the icon of the wallet
+    pub fn icon(&self) -> Option<&str> {
+        self.icon.as_deref()
+    }
+
+    /// Get the homepage of the wallet
+    pub fn homepage_url(&self) -> &str {
+        &self.homepage_url
+    }
+
+    /// Get the URL to install the wallet
+    pub fn install_url(&self) -> &str {
+        &self.install_url
+    }
+
+    /// Get the template of the deeplink
+    pub fn deeplink_template(&self) -> Option<&str> {
+        self.deeplink_template.as_deref()
+    }
+
+    /// Build the deeplink that opens the app at `url`, from the origin `app_ref`,
+    /// in the wallet. Both values are percent-encoded
+    pub fn deeplink(&self, url: &str, app_ref: &str) -> Option<String> {
+        self.deeplink_template.as_ref().map(|template| {
+            template
+                .replace(DEEPLINK_URL_PLACEHOLDER, &percent_encode(url))
+                .replace(DEEPLINK_REF_PLACEHOLDER, &percent_encode(app_ref))
+        })
+    }
+}
+
+/// The known wallets rendered by a wallet picker whether they are installed or not.
+/// Wallet names are compared case-insensitively
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WalletCatalog(Vec<KnownWallet>);
+
+impl WalletCatalog {
+    /// The wallets included in [Self::default]
+    pub const KNOWN_WALLETS: &[KnownWallet] = &[
+        KnownWallet::new_with_deeplink(
+            "Phantom",
+            Option::None,
+            "https://phantom.app",
+            "https://phantom.app/download",
+            "https://phantom.app/ul/browse/{url}?ref={ref}",
+        ),
+        KnownWallet::new_with_deeplink(
+            "Solflare",
+            Option::None,
+            "https://solflare.com",
+            "https://solflare.com/download",
+            "https://solflare.com/ul/v1/browse/{url}?ref={ref}",
+        ),
+        KnownWallet::new(
+            "Backpack",
+            Option::None,
+            "https://backpack.app",
+            "https://backpack.app/downloads",
+        ),
+    ];
+
+    /// A catalog without any wallets
+    pub fn empty() -> Self {
+        Self(Vec::default())
+    }
+
+    /// Add a wallet, replacing a wallet with the same name
+    pub fn add(&mut self, known_wallet: KnownWallet) -> &mut Self {
+        match self
+            .0
+            .iter_mut()
+            .find(|existing| Self::same_name(existing.name(), known_wallet.name()))
+        {
+            Some(existing) => *existing = known_wallet,
+            None => self.0.push(known_wallet),
+        }
+
+        self
+    }
+
+    /// Remove a wallet by name
+    pub fn remove(&mut self, wallet_name: &str) -> Option<KnownWallet> {
+        self.0
+            .iter()
+            .position(|existing| Self::same_name(existing.name(), wallet_name))
+            .map(|position| self.0.remove(position))
+    }
+
+    /// Get a wallet by name
+    pub fn get(&self, wallet_name: &str) -> Option<&KnownWallet> {
+        self.0
+            .iter()
+            .find(|existing| Self::same_name(existing.name(), wallet_name))
+    }
+
+    /// Get all the wallets in the order they were added
+    pub fn wallets(&self) -> &[KnownWallet] {
+        &self.0
+    }
+
+    fn same_name(first: &str, second: &str) -> bool {
+        first.to_lowercase() == second.to_lowercase()
+    }
+}
+
+impl Default for WalletCatalog {
+    fn default() -> Self {
+        Self(Self::KNOWN_WALLETS.to_vec())
+    }
+}
+
+// Percent-encodes everything except the unreserved characters of RFC 3986
+fn percent_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                (byte as char).to_string()
+            }
+            _ => format!("%{byte:02X}"),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod wallet_catalog_tests {
+    use super::*;
+
+    #[test]
+    fn extends_and_overrides_catalog() {
+        let mut catalog = WalletCatalog::default();
+        assert_eq!(catalog.wallets().len(), WalletCatalog::KNOWN_WALLETS.len());
+
+        catalog
+            .add(KnownWallet::new(
+                "phantom",
+                Option::None,
+                "https://example.com",
+                "https://example.com/get",
+            ))
+            .add(
+                KnownWallet::new(
+                    "Mock",
+                    Some("data:image/svg+xml;base64,PHN2Zy8+"),
+                    "https://mock.dev",
+                    "https://mock.dev/get",
+                )
+                .with_icon("data:image/png;base64,iVBORw0KGgo="),
+            );
+        assert_eq!(
+            catalog.get("Phantom").unwrap().install_url(),
+            "https://example.com/get"
+        );
+        assert_eq!(catalog.wallets()[0].name(), "phantom");
+        assert_eq!(catalog.wallets()[0].icon(), None);
+        assert_eq!(catalog.wallets().last().unwrap().name(), "Mock");
+        assert_eq!(
+            catalog.get("Mock").unwrap().icon(),
+            Some("data:image/png;base64,iVBORw0KGgo=")
+        );
+
+        assert!(catalog.remove("MOCK").is_some());
+        assert!(catalog.get("Mock").is_none());
+    }
+
+    #[test]
+    fn builds_deeplinks_and_readiness() {
+        let catalog = WalletCatalog::default();
+        let phantom = catalog.get("Phantom").unwrap();
+
+        assert_eq!(
+            phantom.deeplink("https://app.dev/swap?a=1", "https://app.dev"),
+            Some(
+                "https://phantom.app/ul/browse/https%3A%2F%2Fapp.dev%2Fswap%3Fa%3D1?ref=https%3A%2F%2Fapp.dev"
+                    .to_string()
+            )
+        );
+
+        let backpack = catalog.get("Backpack");
+        assert_eq!(
+            WalletReadiness::new(true, false, Some(phantom)),
+            WalletReadiness::Installed
+        );
+        assert_eq!(
+            WalletReadiness::new(false, true, Some(phantom)),
+            WalletReadiness::Loadable
+        );
+        assert_eq!(
+            WalletReadiness::new(false, false, Some(phantom)),
+            WalletReadiness::NotDetected
+        );
+        assert_eq!(
+            WalletReadiness::new(false, true, backpack),
+            WalletReadiness::NotDetected
+        );
+    }
+}
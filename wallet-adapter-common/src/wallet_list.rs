@@ -0,0 +1,173 @@
+use crate::{chains::ChainSupport, feature_support::FeatureSupport, WalletData};
+
+/// Options to filter and rank a list of wallets for a wallet picker.
+/// Wallets are ranked with the recently used wallet first, then the preferred wallets
+/// in the order they were added, then the rest of the wallets in the order they were given.
+/// Wallet names are compared case-insensitively
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WalletListOptions {
+    recently_used: Option<String>,
+    preferred: Vec<String>,
+    required_features: FeatureSupport,
+    required_chains: ChainSupport,
+}
+
+impl WalletListOptions {
+    /// Instantiate [Self] with no ranking and no filters
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the name of the most recently used wallet which is ranked first
+    pub fn set_recently_used(&mut self, wallet_name: &str) -> &mut Self {
+        self.recently_used.replace(wallet_name.to_string());
+
+        self
+    }
+
+    /// Add a preferred wallet which is ranked after the recently used wallet
+    /// and after the preferred wallets added before it
+    pub fn add_preferred(&mut self, wallet_name: &str) -> &mut Self {
+        self.preferred.push(wallet_name.to_string());
+
+        self
+    }
+
+    /// Only list the wallets that support all the features set in `features`
+    pub fn require_features(&mut self, features: FeatureSupport) -> &mut Self {
+        self.required_features = features;
+
+        self
+    }
+
+    /// Only list the wallets that support all the clusters set in `chains`
+    pub fn require_chains(&mut self, chains: ChainSupport) -> &mut Self {
+        self.required_chains = chains;
+
+        self
+    }
+
+    /// Get the name of the most recently used wallet
+    pub fn recently_used(&self) -> Option<&str> {
+        self.recently_used.as_deref()
+    }
+
+    /// Get the names of the preferred wallets
+    pub fn preferred(&self) -> &[String] {
+        &self.preferred
+    }
+
+    /// Get the features a wallet is required to support
+    pub fn required_features(&self) -> &FeatureSupport {
+        &self.required_features
+    }
+
+    /// Get the clusters a wallet is required to support
+    pub fn required_chains(&self) -> &ChainSupport {
+        &self.required_chains
+    }
+
+    /// Check whether the wallet supports the required features and chains
+    pub fn matches(&self, wallet: &WalletData) -> bool {
+        wallet
+            .supported_features()
+            .contains(&self.required_features)
+            && wallet.supported_chains().contains(&self.required_chains)
+    }
+
+    /// The rank of a wallet, a lower rank is listed first
+    pub fn rank(&self, wallet_name: &str) -> usize {
+        let wallet_name = wallet_name.to_lowercase();
+
+        if self
+            .recently_used
+            .as_ref()
+            .is_some_and(|recently_used| recently_used.to_lowercase() == wallet_name)
+        {
+            return 0;
+        }
+
+        self.preferred
+            .iter()
+            .position(|preferred| preferred.to_lowercase() == wallet_name)
+            .map(|position| position + 1)
+            .unwrap_or(usize::MAX)
+    }
+
+    /// Filter the `wallets` and sort them by [Self::rank].
+    /// Wallets with the same rank keep the order they were given in
+    pub fn apply<T>(&self, wallets: Vec<T>, data: impl Fn(&T) -> &WalletData) -> Vec<T> {
+        let mut wallets = wallets
+            .into_iter()
+            .filter(|wallet| self.matches(data(wallet)))
+            .collect::<Vec<T>>();
+
+        wallets.sort_by_key(|wallet| self.rank(data(wallet).name()));
+
+        wallets
+    }
+}
+
+#[cfg(test)]
+mod wallet_list_tests {
+    use super::*;
+
+    fn wallet(name: &str, sign_in: bool, mainnet: bool) -> WalletData {
+        WalletData::new()
+            .set_name(name)
+            .set_supported_features(FeatureSupport {
+                connect: true,
+                sign_in,
+                ..Default::default()
+            })
+            .set_supported_chains(ChainSupport {
+                mainnet,
+                devnet: true,
+                ..Default::default()
+            })
+    }
+
+    fn names(wallets: &[WalletData]) -> Vec<&str> {
+        wallets.iter().map(|wallet| wallet.name()).collect()
+    }
+
+    #[test]
+    fn ranks_and_filters_wallets() {
+        let wallets = vec![
+            wallet("Alpha", true, true),
+            wallet("Beta", false, true),
+            wallet("Gamma", true, false),
+            wallet("Delta", true, true),
+        ];
+
+        let options = WalletListOptions::new();
+        assert_eq!(
+            names(&options.apply(wallets.clone(), |wallet| wallet)),
+            ["Alpha", "Beta", "Gamma", "Delta"]
+        );
+
+        let mut options = WalletListOptions::new();
+        options
+            .set_recently_used("delta")
+            .add_preferred("Gamma")
+            .add_preferred("Beta");
+        assert_eq!(
+            names(&options.apply(wallets.clone(), |wallet| wallet)),
+            ["Delta", "Gamma", "Beta", "Alpha"]
+        );
+
+        options
+            .require_features(FeatureSupport {
+                sign_in: true,
+                ..Default::default()
+            })
+            .require_chains(ChainSupport {
+                mainnet: true,
+                ..Default::default()
+            });
+        assert_eq!(
+            names(&options.apply(wallets, |wallet| wallet)),
+            ["Delta", "Alpha"]
+        );
+    }
+}